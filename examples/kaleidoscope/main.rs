@@ -1142,9 +1142,7 @@ impl<'a> Compiler<'a> {
 
             Ok(function)
         } else {
-            unsafe {
-                function.delete();
-            }
+            function.delete().expect("Function should only be used by its own body");
 
             Err("Invalid generated function.")
         }
@@ -0,0 +1,789 @@
+//! Control flow analyses computed over a `FunctionValue`, such as its dominator tree and
+//! natural loops. LLVM's own analyses aren't exposed through its C API, so these are
+//! computed from the CFG as seen through `BasicBlock::successors`.
+//!
+//! Every analysis is a snapshot. It must be recomputed whenever the CFG of the function changes.
+
+use llvm_sys::core::{LLVMGetInstructionParent, LLVMIsAInstruction};
+use llvm_sys::prelude::LLVMBasicBlockRef;
+
+use basic_block::BasicBlock;
+use values::{BasicValue, FunctionValue};
+
+use std::collections::HashMap;
+
+// BasicBlock isn't Copy or Hash, so blocks are tracked by index into their layout order
+#[derive(Debug)]
+struct ControlFlowGraph {
+    blocks: Vec<LLVMBasicBlockRef>,
+    indices: HashMap<LLVMBasicBlockRef, usize>,
+    successors: Vec<Vec<usize>>,
+    predecessors: Vec<Vec<usize>>,
+}
+
+impl ControlFlowGraph {
+    fn new(function: &FunctionValue) -> Self {
+        let blocks: Vec<LLVMBasicBlockRef> = function.basic_blocks().map(|basic_block| basic_block.basic_block).collect();
+        let indices: HashMap<LLVMBasicBlockRef, usize> = blocks.iter().enumerate().map(|(index, &basic_block)| (basic_block, index)).collect();
+        let mut successors = Vec::with_capacity(blocks.len());
+        let mut predecessors = vec![Vec::new(); blocks.len()];
+
+        for (index, &basic_block) in blocks.iter().enumerate() {
+            let block_successors: Vec<usize> = to_basic_block(basic_block).successors()
+                .iter()
+                .filter_map(|successor| indices.get(&successor.basic_block).cloned())
+                .collect();
+
+            for &successor in &block_successors {
+                predecessors[successor].push(index);
+            }
+
+            successors.push(block_successors);
+        }
+
+        ControlFlowGraph {
+            blocks,
+            indices,
+            successors,
+            predecessors,
+        }
+    }
+
+    fn get_index(&self, basic_block: &BasicBlock) -> Option<usize> {
+        self.indices.get(&basic_block.basic_block).cloned()
+    }
+
+    fn get_block(&self, index: usize) -> BasicBlock {
+        to_basic_block(self.blocks[index])
+    }
+
+    fn get_blocks(&self, indices: &[usize]) -> Vec<BasicBlock> {
+        indices.iter().map(|&index| self.get_block(index)).collect()
+    }
+}
+
+fn to_basic_block(basic_block: LLVMBasicBlockRef) -> BasicBlock {
+    BasicBlock::new(basic_block).expect("Analyzed basic blocks should never be null")
+}
+
+// A dominator tree over a graph of `successors.len()` nodes, which may include a virtual
+// root joining several real roots together
+#[derive(Debug)]
+struct DominatorTreeData {
+    root: usize,
+    // Nodes reachable from the root, in reverse postorder
+    order: Vec<usize>,
+    // Every reachable node's immediate dominator, where the root is its own
+    immediate_dominators: Vec<Option<usize>>,
+    children: Vec<Vec<usize>>,
+    // Preorder and postorder numbers of each node in the tree, for constant time dominance checks
+    preorder: Vec<usize>,
+    postorder: Vec<usize>,
+}
+
+impl DominatorTreeData {
+    // Computes the tree with the iterative algorithm from "A Simple, Fast Dominance Algorithm"
+    // by Cooper, Harvey and Kennedy
+    fn new(successors: &[Vec<usize>], predecessors: &[Vec<usize>], root: usize) -> Self {
+        let num_nodes = successors.len();
+        let order = reverse_postorder(successors, root);
+        let mut order_numbers = vec![usize::max_value(); num_nodes];
+
+        for (number, &node) in order.iter().enumerate() {
+            order_numbers[node] = number;
+        }
+
+        let mut immediate_dominators = vec![None; num_nodes];
+
+        immediate_dominators[root] = Some(root);
+
+        let mut changed = true;
+
+        while changed {
+            changed = false;
+
+            for &node in order.iter().skip(1) {
+                let mut new_immediate_dominator = None;
+
+                for &predecessor in &predecessors[node] {
+                    if immediate_dominators[predecessor].is_none() {
+                        continue;
+                    }
+
+                    new_immediate_dominator = Some(match new_immediate_dominator {
+                        Some(other) => intersect(&immediate_dominators, &order_numbers, predecessor, other),
+                        None => predecessor,
+                    });
+                }
+
+                if new_immediate_dominator.is_some() && immediate_dominators[node] != new_immediate_dominator {
+                    immediate_dominators[node] = new_immediate_dominator;
+                    changed = true;
+                }
+            }
+        }
+
+        let mut children = vec![Vec::new(); num_nodes];
+
+        for &node in order.iter().skip(1) {
+            if let Some(immediate_dominator) = immediate_dominators[node] {
+                children[immediate_dominator].push(node);
+            }
+        }
+
+        let mut preorder = vec![0; num_nodes];
+        let mut postorder = vec![0; num_nodes];
+        let mut counter = 0;
+        let mut stack = vec![(root, false)];
+
+        while let Some((node, visited)) = stack.pop() {
+            counter += 1;
+
+            if visited {
+                postorder[node] = counter;
+                continue;
+            }
+
+            preorder[node] = counter;
+            stack.push((node, true));
+
+            for &child in children[node].iter().rev() {
+                stack.push((child, false));
+            }
+        }
+
+        DominatorTreeData {
+            root,
+            order,
+            immediate_dominators,
+            children,
+            preorder,
+            postorder,
+        }
+    }
+
+    fn is_reachable(&self, node: usize) -> bool {
+        self.immediate_dominators[node].is_some()
+    }
+
+    fn dominates(&self, dominator: usize, node: usize) -> bool {
+        if !self.is_reachable(dominator) || !self.is_reachable(node) {
+            return false;
+        }
+
+        self.preorder[dominator] <= self.preorder[node] && self.postorder[node] <= self.postorder[dominator]
+    }
+}
+
+fn reverse_postorder(successors: &[Vec<usize>], root: usize) -> Vec<usize> {
+    let mut visited = vec![false; successors.len()];
+    let mut postorder = Vec::with_capacity(successors.len());
+    let mut stack = vec![(root, 0)];
+
+    visited[root] = true;
+
+    while let Some((node, next_successor)) = stack.pop() {
+        match successors[node].get(next_successor) {
+            Some(&successor) => {
+                stack.push((node, next_successor + 1));
+
+                if !visited[successor] {
+                    visited[successor] = true;
+                    stack.push((successor, 0));
+                }
+            },
+            None => postorder.push(node),
+        }
+    }
+
+    postorder.reverse();
+    postorder
+}
+
+fn intersect(immediate_dominators: &[Option<usize>], order_numbers: &[usize], mut left: usize, mut right: usize) -> usize {
+    while left != right {
+        while order_numbers[left] > order_numbers[right] {
+            left = immediate_dominators[left].expect("Intersected nodes should have been processed");
+        }
+
+        while order_numbers[right] > order_numbers[left] {
+            right = immediate_dominators[right].expect("Intersected nodes should have been processed");
+        }
+    }
+
+    left
+}
+
+/// A `DominatorTree` records which `BasicBlock`s of a `FunctionValue` dominate each other.
+/// A `BasicBlock` dominates another if every path from the entry block to the latter passes
+/// through the former. Every `BasicBlock` dominates itself.
+///
+/// `BasicBlock`s which can't be reached from the entry block neither dominate nor are
+/// dominated by anything.
+///
+/// # Example
+/// ```no_run
+/// use inkwell::analysis::DominatorTree;
+/// use inkwell::context::Context;
+///
+/// let context = Context::create();
+/// let module = context.create_module("my_mod");
+/// let builder = context.create_builder();
+/// let void_type = context.void_type();
+/// let bool_type = context.bool_type();
+/// let fn_type = void_type.fn_type(&[&bool_type], false);
+/// let function = module.add_function("my_fn", &fn_type, None);
+/// let entry = function.append_basic_block("entry");
+/// let then_block = function.append_basic_block("then");
+/// let merge = function.append_basic_block("merge");
+/// let condition = function.get_first_param().unwrap().into_int_value();
+///
+/// builder.position_at_end(&entry);
+/// builder.build_conditional_branch(&condition, &then_block, &merge);
+/// builder.position_at_end(&then_block);
+/// builder.build_unconditional_branch(&merge);
+/// builder.position_at_end(&merge);
+/// builder.build_return(None);
+///
+/// let dominator_tree = DominatorTree::new(&function);
+///
+/// assert!(dominator_tree.dominates(&entry, &merge));
+/// assert!(!dominator_tree.dominates(&then_block, &merge));
+/// assert_eq!(dominator_tree.get_immediate_dominator(&merge), Some(entry));
+/// assert_eq!(dominator_tree.get_dominance_frontier(&then_block), vec![merge]);
+/// ```
+#[derive(Debug)]
+pub struct DominatorTree {
+    cfg: ControlFlowGraph,
+    tree: DominatorTreeData,
+    dominance_frontiers: Vec<Vec<usize>>,
+}
+
+impl DominatorTree {
+    /// Computes the `DominatorTree` of `function`, along with its dominance frontiers.
+    /// A declaration has an empty `DominatorTree`.
+    pub fn new(function: &FunctionValue) -> Self {
+        let cfg = ControlFlowGraph::new(function);
+
+        // An extra root without any edges stands in for the missing entry block of a declaration
+        let (tree, dominance_frontiers) = if cfg.blocks.is_empty() {
+            (DominatorTreeData::new(&[Vec::new()], &[Vec::new()], 0), Vec::new())
+        } else {
+            let tree = DominatorTreeData::new(&cfg.successors, &cfg.predecessors, 0);
+            let dominance_frontiers = compute_dominance_frontiers(&tree, &cfg.predecessors);
+
+            (tree, dominance_frontiers)
+        };
+
+        DominatorTree {
+            cfg,
+            tree,
+            dominance_frontiers,
+        }
+    }
+
+    /// Gets the root of this `DominatorTree`, which is the entry block of the `FunctionValue`.
+    pub fn get_root(&self) -> Option<BasicBlock> {
+        if self.cfg.blocks.is_empty() {
+            return None;
+        }
+
+        Some(self.cfg.get_block(self.tree.root))
+    }
+
+    /// Determines whether `basic_block` can be reached from the entry block. Returns `false`
+    /// for `BasicBlock`s from other `FunctionValue`s.
+    pub fn is_reachable(&self, basic_block: &BasicBlock) -> bool {
+        self.cfg.get_index(basic_block).map_or(false, |index| self.tree.is_reachable(index))
+    }
+
+    /// Gets the closest `BasicBlock` which strictly dominates `basic_block`, if any.
+    /// The entry block has no immediate dominator.
+    pub fn get_immediate_dominator(&self, basic_block: &BasicBlock) -> Option<BasicBlock> {
+        let index = match self.cfg.get_index(basic_block) {
+            Some(index) => index,
+            None => return None,
+        };
+
+        match self.tree.immediate_dominators[index] {
+            Some(immediate_dominator) if immediate_dominator != index => Some(self.cfg.get_block(immediate_dominator)),
+            _ => None,
+        }
+    }
+
+    /// Gets the `BasicBlock`s immediately dominated by `basic_block`, in reverse postorder.
+    pub fn get_children(&self, basic_block: &BasicBlock) -> Vec<BasicBlock> {
+        match self.cfg.get_index(basic_block) {
+            Some(index) => self.cfg.get_blocks(&self.tree.children[index]),
+            None => Vec::new(),
+        }
+    }
+
+    /// Determines whether `dominator` dominates `basic_block`. This is always the case
+    /// when both are the same reachable `BasicBlock`.
+    pub fn dominates(&self, dominator: &BasicBlock, basic_block: &BasicBlock) -> bool {
+        match (self.cfg.get_index(dominator), self.cfg.get_index(basic_block)) {
+            (Some(dominator), Some(basic_block)) => self.tree.dominates(dominator, basic_block),
+            _ => false,
+        }
+    }
+
+    /// Determines whether `dominator` dominates `basic_block` without being the same `BasicBlock`.
+    pub fn strictly_dominates(&self, dominator: &BasicBlock, basic_block: &BasicBlock) -> bool {
+        dominator != basic_block && self.dominates(dominator, basic_block)
+    }
+
+    /// Gets the reachable `BasicBlock`s of the `FunctionValue` in reverse postorder, so that
+    /// every `BasicBlock` comes after its dominators.
+    pub fn get_reverse_postorder(&self) -> Vec<BasicBlock> {
+        if self.cfg.blocks.is_empty() {
+            return Vec::new();
+        }
+
+        self.cfg.get_blocks(&self.tree.order)
+    }
+
+    /// Gets the dominance frontier of `basic_block`: the `BasicBlock`s it doesn't strictly
+    /// dominate, but which have a predecessor it does dominate. This is where the control
+    /// flow from `basic_block` merges with other paths, such as where phi nodes are needed
+    /// for a variable assigned in `basic_block`.
+    pub fn get_dominance_frontier(&self, basic_block: &BasicBlock) -> Vec<BasicBlock> {
+        match self.cfg.get_index(basic_block) {
+            Some(index) => self.cfg.get_blocks(&self.dominance_frontiers[index]),
+            None => Vec::new(),
+        }
+    }
+
+    /// Gets the iterated dominance frontier of `basic_blocks`: their dominance frontiers,
+    /// the frontiers of those, and so on. When a variable is assigned in each of
+    /// `basic_blocks`, these are exactly the `BasicBlock`s needing a phi node for it.
+    pub fn get_iterated_dominance_frontier(&self, basic_blocks: &[&BasicBlock]) -> Vec<BasicBlock> {
+        let mut in_frontier = vec![false; self.cfg.blocks.len()];
+        let mut worklist: Vec<usize> = basic_blocks.iter().filter_map(|basic_block| self.cfg.get_index(basic_block)).collect();
+        let mut frontier = Vec::new();
+
+        while let Some(index) = worklist.pop() {
+            for &frontier_index in &self.dominance_frontiers[index] {
+                if !in_frontier[frontier_index] {
+                    in_frontier[frontier_index] = true;
+                    frontier.push(frontier_index);
+                    worklist.push(frontier_index);
+                }
+            }
+        }
+
+        frontier.sort();
+
+        self.cfg.get_blocks(&frontier)
+    }
+}
+
+// Computes the dominance frontier of every node, in layout order, using the algorithm from
+// "A Simple, Fast Dominance Algorithm" by Cooper, Harvey and Kennedy
+fn compute_dominance_frontiers(tree: &DominatorTreeData, predecessors: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let mut dominance_frontiers = vec![Vec::new(); predecessors.len()];
+
+    for &node in &tree.order {
+        let reachable_predecessors: Vec<usize> = predecessors[node].iter()
+            .cloned()
+            .filter(|&predecessor| tree.is_reachable(predecessor))
+            .collect();
+
+        if reachable_predecessors.len() < 2 {
+            continue;
+        }
+
+        let immediate_dominator = tree.immediate_dominators[node];
+
+        for predecessor in reachable_predecessors {
+            let mut runner = predecessor;
+
+            while Some(runner) != immediate_dominator {
+                if !dominance_frontiers[runner].contains(&node) {
+                    dominance_frontiers[runner].push(node);
+                }
+
+                // The entry block can't have predecessors in valid IR, so the walk
+                // should never need to go past the root
+                match tree.immediate_dominators[runner] {
+                    Some(next) if next != runner => runner = next,
+                    _ => break,
+                }
+            }
+        }
+    }
+
+    for dominance_frontier in &mut dominance_frontiers {
+        dominance_frontier.sort();
+    }
+
+    dominance_frontiers
+}
+
+/// A `PostDominatorTree` records which `BasicBlock`s of a `FunctionValue` post-dominate each other.
+/// A `BasicBlock` post-dominates another if every path from the latter to an exit of the
+/// `FunctionValue`, such as a `ret` or `unreachable`, passes through the former.
+///
+/// A `FunctionValue` may have several exits, so the tree may have several roots. `BasicBlock`s
+/// which can't reach an exit, such as those in an infinite loop, neither post-dominate nor
+/// are post-dominated by anything.
+#[derive(Debug)]
+pub struct PostDominatorTree {
+    cfg: ControlFlowGraph,
+    // Built over the reversed CFG, with an additional virtual root leading to every exit
+    tree: DominatorTreeData,
+}
+
+impl PostDominatorTree {
+    /// Computes the `PostDominatorTree` of `function`.
+    pub fn new(function: &FunctionValue) -> Self {
+        let cfg = ControlFlowGraph::new(function);
+        let virtual_root = cfg.blocks.len();
+        let mut successors = cfg.predecessors.clone();
+        let mut predecessors = cfg.successors.clone();
+        let exits: Vec<usize> = (0..virtual_root).filter(|&index| cfg.successors[index].is_empty()).collect();
+
+        for &exit in &exits {
+            predecessors[exit].push(virtual_root);
+        }
+
+        successors.push(exits);
+        predecessors.push(Vec::new());
+
+        let tree = DominatorTreeData::new(&successors, &predecessors, virtual_root);
+
+        PostDominatorTree {
+            cfg,
+            tree,
+        }
+    }
+
+    /// Gets the roots of this `PostDominatorTree`, which are the `BasicBlock`s without any
+    /// successors, in layout order.
+    pub fn get_roots(&self) -> Vec<BasicBlock> {
+        // Blocks whose paths lead to different exits also hang off the virtual root,
+        // despite not being exits themselves
+        let mut roots: Vec<usize> = self.tree.children[self.tree.root].iter()
+            .cloned()
+            .filter(|&index| self.cfg.successors[index].is_empty())
+            .collect();
+
+        roots.sort();
+
+        self.cfg.get_blocks(&roots)
+    }
+
+    /// Determines whether an exit of the `FunctionValue` can be reached from `basic_block`.
+    pub fn is_reachable(&self, basic_block: &BasicBlock) -> bool {
+        self.cfg.get_index(basic_block).map_or(false, |index| self.tree.is_reachable(index))
+    }
+
+    /// Gets the closest `BasicBlock` which strictly post-dominates `basic_block`, if any.
+    /// Roots have no immediate post-dominator.
+    pub fn get_immediate_post_dominator(&self, basic_block: &BasicBlock) -> Option<BasicBlock> {
+        let index = match self.cfg.get_index(basic_block) {
+            Some(index) => index,
+            None => return None,
+        };
+
+        match self.tree.immediate_dominators[index] {
+            Some(immediate_post_dominator) if immediate_post_dominator != self.tree.root => Some(self.cfg.get_block(immediate_post_dominator)),
+            _ => None,
+        }
+    }
+
+    /// Gets the `BasicBlock`s immediately post-dominated by `basic_block`.
+    pub fn get_children(&self, basic_block: &BasicBlock) -> Vec<BasicBlock> {
+        match self.cfg.get_index(basic_block) {
+            Some(index) => self.get_tree_children(index),
+            None => Vec::new(),
+        }
+    }
+
+    /// Determines whether `post_dominator` post-dominates `basic_block`. This is always the
+    /// case when both are the same `BasicBlock`, as long as it can reach an exit.
+    pub fn post_dominates(&self, post_dominator: &BasicBlock, basic_block: &BasicBlock) -> bool {
+        match (self.cfg.get_index(post_dominator), self.cfg.get_index(basic_block)) {
+            (Some(post_dominator), Some(basic_block)) => self.tree.dominates(post_dominator, basic_block),
+            _ => false,
+        }
+    }
+
+    /// Determines whether `post_dominator` post-dominates `basic_block` without being the same `BasicBlock`.
+    pub fn strictly_post_dominates(&self, post_dominator: &BasicBlock, basic_block: &BasicBlock) -> bool {
+        post_dominator != basic_block && self.post_dominates(post_dominator, basic_block)
+    }
+
+    fn get_tree_children(&self, index: usize) -> Vec<BasicBlock> {
+        let mut children = self.tree.children[index].clone();
+
+        children.sort();
+
+        self.cfg.get_blocks(&children)
+    }
+}
+
+/// A natural loop of a `FunctionValue`: a set of `BasicBlock`s with a single entry, the
+/// header, which dominates all of them, and at least one back edge leading to the header.
+/// Back edges leading to the same header are considered part of the same `Loop`.
+/// See `LoopInfo`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Loop {
+    index: usize,
+    parent: Option<usize>,
+    depth: u32,
+    header: LLVMBasicBlockRef,
+    // In reverse postorder, starting with the header
+    blocks: Vec<LLVMBasicBlockRef>,
+    latches: Vec<LLVMBasicBlockRef>,
+    exiting_blocks: Vec<LLVMBasicBlockRef>,
+    exit_blocks: Vec<LLVMBasicBlockRef>,
+}
+
+impl Loop {
+    /// Gets the header of this `Loop`, which is its only entry.
+    pub fn get_header(&self) -> BasicBlock {
+        to_basic_block(self.header)
+    }
+
+    /// Gets every `BasicBlock` in this `Loop`, including those of nested `Loop`s, in reverse
+    /// postorder. The header always comes first.
+    pub fn get_blocks(&self) -> Vec<BasicBlock> {
+        self.blocks.iter().map(|&basic_block| to_basic_block(basic_block)).collect()
+    }
+
+    /// Gets the `BasicBlock`s in this `Loop` which branch back to the header.
+    pub fn get_latches(&self) -> Vec<BasicBlock> {
+        self.latches.iter().map(|&basic_block| to_basic_block(basic_block)).collect()
+    }
+
+    /// Gets the `BasicBlock`s in this `Loop` which have a successor outside of it.
+    pub fn get_exiting_blocks(&self) -> Vec<BasicBlock> {
+        self.exiting_blocks.iter().map(|&basic_block| to_basic_block(basic_block)).collect()
+    }
+
+    /// Gets the `BasicBlock`s outside of this `Loop` which are successors of a `BasicBlock` in it.
+    pub fn get_exit_blocks(&self) -> Vec<BasicBlock> {
+        self.exit_blocks.iter().map(|&basic_block| to_basic_block(basic_block)).collect()
+    }
+
+    /// Gets the nesting depth of this `Loop`, which is one for a `Loop` not nested in any other.
+    pub fn get_depth(&self) -> u32 {
+        self.depth
+    }
+
+    /// Determines whether `basic_block` is part of this `Loop`, including any nested `Loop`s.
+    pub fn contains(&self, basic_block: &BasicBlock) -> bool {
+        self.blocks.contains(&basic_block.basic_block)
+    }
+
+    /// Determines whether `value` is invariant with respect to this `Loop`, meaning it
+    /// isn't computed by an instruction in this `Loop`. Constants, arguments and
+    /// instructions outside of this `Loop` are all loop invariant.
+    pub fn is_invariant(&self, value: &BasicValue) -> bool {
+        let value = value.as_value_ref();
+
+        let is_instruction = unsafe {
+            !LLVMIsAInstruction(value).is_null()
+        };
+
+        if !is_instruction {
+            return true;
+        }
+
+        let parent = unsafe {
+            LLVMGetInstructionParent(value)
+        };
+
+        !self.blocks.contains(&parent)
+    }
+}
+
+/// `LoopInfo` finds the natural `Loop`s of a `FunctionValue` and how they nest, using its
+/// `DominatorTree`. Only back edges to a dominating header form a `Loop`, so cycles which
+/// can be entered in several places (irreducible control flow) are not considered `Loop`s.
+///
+/// # Example
+/// ```no_run
+/// use inkwell::analysis::{DominatorTree, LoopInfo};
+/// use inkwell::context::Context;
+///
+/// let context = Context::create();
+/// let module = context.create_module("my_mod");
+/// let builder = context.create_builder();
+/// let void_type = context.void_type();
+/// let bool_type = context.bool_type();
+/// let fn_type = void_type.fn_type(&[&bool_type], false);
+/// let function = module.add_function("my_fn", &fn_type, None);
+/// let entry = function.append_basic_block("entry");
+/// let body = function.append_basic_block("body");
+/// let exit = function.append_basic_block("exit");
+/// let condition = function.get_first_param().unwrap().into_int_value();
+///
+/// builder.position_at_end(&entry);
+/// builder.build_unconditional_branch(&body);
+/// builder.position_at_end(&body);
+/// builder.build_conditional_branch(&condition, &body, &exit);
+/// builder.position_at_end(&exit);
+/// builder.build_return(None);
+///
+/// let dominator_tree = DominatorTree::new(&function);
+/// let loop_info = LoopInfo::new(&dominator_tree);
+/// let loop_ = loop_info.get_loop_for(&body).unwrap();
+///
+/// assert_eq!(loop_.get_header(), body);
+/// assert_eq!(loop_.get_latches(), vec![body]);
+/// assert_eq!(loop_.get_exit_blocks(), vec![exit]);
+/// assert_eq!(loop_info.get_loop_depth(&entry), 0);
+/// ```
+#[derive(Debug)]
+pub struct LoopInfo {
+    // Outer loops always come before the loops nested inside of them
+    loops: Vec<Loop>,
+    indices: HashMap<LLVMBasicBlockRef, usize>,
+    // The innermost loop of each basic block, by layout index
+    innermost_loops: Vec<Option<usize>>,
+}
+
+impl LoopInfo {
+    /// Finds the `Loop`s of the `FunctionValue` `dominator_tree` was computed for.
+    pub fn new(dominator_tree: &DominatorTree) -> Self {
+        let cfg = &dominator_tree.cfg;
+        let tree = &dominator_tree.tree;
+        let num_blocks = cfg.blocks.len();
+        let mut order_numbers = vec![usize::max_value(); num_blocks];
+
+        // The tree of a declaration only holds a placeholder root
+        let order: &[usize] = if num_blocks == 0 { &[] } else { &tree.order };
+
+        for (number, &index) in order.iter().enumerate() {
+            order_numbers[index] = number;
+        }
+
+        // Headers dominate their loops, so visiting them in reverse postorder
+        // finds every loop before those nested inside of it
+        let mut loop_bodies: Vec<(usize, Vec<bool>, Vec<usize>)> = Vec::new();
+
+        for &header in order {
+            let latches: Vec<usize> = cfg.predecessors[header].iter()
+                .cloned()
+                .filter(|&predecessor| tree.dominates(header, predecessor))
+                .collect();
+
+            if latches.is_empty() {
+                continue;
+            }
+
+            // Walk backwards from each latch until reaching the header
+            let mut in_loop = vec![false; num_blocks];
+            let mut worklist = latches.clone();
+
+            in_loop[header] = true;
+
+            while let Some(index) = worklist.pop() {
+                if in_loop[index] {
+                    continue;
+                }
+
+                in_loop[index] = true;
+
+                worklist.extend(cfg.predecessors[index].iter().cloned().filter(|&predecessor| tree.is_reachable(predecessor)));
+            }
+
+            loop_bodies.push((header, in_loop, latches));
+        }
+
+        let mut loops: Vec<Loop> = Vec::with_capacity(loop_bodies.len());
+        let mut innermost_loops = vec![None; num_blocks];
+
+        for (index, &(header, ref in_loop, ref latches)) in loop_bodies.iter().enumerate() {
+            // The closest enclosing loop is the innermost one seen so far containing the header
+            let parent = innermost_loops[header];
+            let depth = parent.map_or(1, |parent: usize| loops[parent].depth + 1);
+            let mut blocks: Vec<usize> = (0..num_blocks).filter(|&block| in_loop[block]).collect();
+            let mut exiting_blocks = Vec::new();
+            let mut exit_blocks = Vec::new();
+
+            blocks.sort_by_key(|&block| order_numbers[block]);
+
+            for &block in &blocks {
+                innermost_loops[block] = Some(index);
+
+                for &successor in &cfg.successors[block] {
+                    if in_loop[successor] {
+                        continue;
+                    }
+
+                    if !exiting_blocks.contains(&block) {
+                        exiting_blocks.push(block);
+                    }
+
+                    if !exit_blocks.contains(&successor) {
+                        exit_blocks.push(successor);
+                    }
+                }
+            }
+
+            let mut latches = latches.clone();
+
+            latches.sort_by_key(|&block| order_numbers[block]);
+            exit_blocks.sort_by_key(|&block| order_numbers[block]);
+
+            let to_refs = |indices: &[usize]| -> Vec<LLVMBasicBlockRef> { indices.iter().map(|&index| cfg.blocks[index]).collect() };
+
+            loops.push(Loop {
+                index,
+                parent,
+                depth,
+                header: cfg.blocks[header],
+                blocks: to_refs(&blocks),
+                latches: to_refs(&latches),
+                exiting_blocks: to_refs(&exiting_blocks),
+                exit_blocks: to_refs(&exit_blocks),
+            });
+        }
+
+        LoopInfo {
+            loops,
+            indices: cfg.indices.clone(),
+            innermost_loops,
+        }
+    }
+
+    /// Gets every `Loop`, with outer `Loop`s always coming before those nested inside of them.
+    pub fn get_loops(&self) -> &[Loop] {
+        &self.loops
+    }
+
+    /// Gets the `Loop`s which aren't nested in any other `Loop`.
+    pub fn get_top_level_loops(&self) -> Vec<&Loop> {
+        self.loops.iter().filter(|loop_| loop_.parent.is_none()).collect()
+    }
+
+    /// Gets the innermost `Loop` containing `basic_block`, if any.
+    pub fn get_loop_for(&self, basic_block: &BasicBlock) -> Option<&Loop> {
+        self.indices.get(&basic_block.basic_block)
+            .and_then(|&index| self.innermost_loops[index])
+            .map(|loop_index| &self.loops[loop_index])
+    }
+
+    /// Gets the number of `Loop`s containing `basic_block`, which is zero outside of any `Loop`.
+    pub fn get_loop_depth(&self, basic_block: &BasicBlock) -> u32 {
+        self.get_loop_for(basic_block).map_or(0, |loop_| loop_.depth)
+    }
+
+    /// Determines whether `basic_block` is the header of a `Loop`.
+    pub fn is_loop_header(&self, basic_block: &BasicBlock) -> bool {
+        self.get_loop_for(basic_block).map_or(false, |loop_| loop_.header == basic_block.basic_block)
+    }
+
+    /// Gets the `Loop` which `loop_` is directly nested in, if any.
+    pub fn get_parent_loop(&self, loop_: &Loop) -> Option<&Loop> {
+        loop_.parent.map(|parent| &self.loops[parent])
+    }
+
+    /// Gets the `Loop`s directly nested in `loop_`.
+    pub fn get_sub_loops(&self, loop_: &Loop) -> Vec<&Loop> {
+        self.loops.iter().filter(|sub_loop| sub_loop.parent == Some(loop_.index)).collect()
+    }
+}
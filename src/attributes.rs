@@ -0,0 +1,196 @@
+//! `Attribute`s are optional modifiers to functions, function parameters, and return types.
+
+use libc::c_char;
+use llvm_sys::core::{LLVMGetEnumAttributeKindForName, LLVMGetLastEnumAttributeKind, LLVMGetEnumAttributeKind, LLVMGetEnumAttributeValue, LLVMGetStringAttributeKind, LLVMGetStringAttributeValue, LLVMIsEnumAttribute, LLVMIsStringAttribute};
+use llvm_sys::prelude::LLVMAttributeRef;
+
+use std::ffi::CStr;
+
+/// Functions, function parameters, and return types can have `Attribute`s to indicate
+/// how they should be treated by optimizations and code generation.
+///
+/// An `Attribute` is either an enum attribute, such as `noinline` or `sret`, which is
+/// identified by a kind id and carries an integer value, or a string attribute, such
+/// as `"target-cpu"="x86-64"`, which is a key/value pair of strings.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Attribute {
+    pub(crate) attribute: LLVMAttributeRef,
+}
+
+impl Attribute {
+    pub(crate) fn new(attribute: LLVMAttributeRef) -> Self {
+        debug_assert!(!attribute.is_null());
+
+        Attribute {
+            attribute,
+        }
+    }
+
+    /// Determines whether or not an `Attribute` is an enum attribute. This method will
+    /// likely be removed in the future in favor of `Attribute`s being generically defined.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::attributes::Attribute;
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let enum_attribute = context.create_enum_attribute(Attribute::get_named_enum_kind_id("noinline"), 0);
+    ///
+    /// assert!(enum_attribute.is_enum());
+    /// ```
+    pub fn is_enum(&self) -> bool {
+        unsafe {
+            LLVMIsEnumAttribute(self.attribute) == 1
+        }
+    }
+
+    /// Determines whether or not an `Attribute` is a string attribute. This method will
+    /// likely be removed in the future in favor of `Attribute`s being generically defined.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let string_attribute = context.create_string_attribute("my_key_123", "my_val");
+    ///
+    /// assert!(string_attribute.is_string());
+    /// ```
+    pub fn is_string(&self) -> bool {
+        unsafe {
+            LLVMIsStringAttribute(self.attribute) == 1
+        }
+    }
+
+    /// Gets the enum kind id associated with a builtin name, such as "noinline" or "sret".
+    /// Returns zero if no such attribute exists.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::attributes::Attribute;
+    ///
+    /// // This kind id doesn't exist:
+    /// assert_eq!(Attribute::get_named_enum_kind_id("foobar"), 0);
+    ///
+    /// // These are real kind ids:
+    /// assert_ne!(Attribute::get_named_enum_kind_id("align"), 0);
+    /// assert_ne!(Attribute::get_named_enum_kind_id("builtin"), 0);
+    /// ```
+    pub fn get_named_enum_kind_id(name: &str) -> u32 {
+        unsafe {
+            LLVMGetEnumAttributeKindForName(name.as_ptr() as *const c_char, name.len())
+        }
+    }
+
+    /// Gets the kind id associated with an enum `Attribute`, or zero for a string `Attribute`.
+    pub fn get_enum_kind_id(&self) -> u32 {
+        unsafe {
+            LLVMGetEnumAttributeKind(self.attribute)
+        }
+    }
+
+    /// Gets the last enum kind id known to LLVM. Valid kind ids range from one up to and
+    /// including this value.
+    pub fn get_last_enum_kind_id() -> u32 {
+        unsafe {
+            LLVMGetLastEnumAttributeKind()
+        }
+    }
+
+    /// Gets the value associated with an enum `Attribute`, such as the alignment of `align`.
+    /// Attributes which don't carry a value, such as `noinline`, have a value of zero.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::attributes::Attribute;
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let enum_attribute = context.create_enum_attribute(Attribute::get_named_enum_kind_id("align"), 16);
+    ///
+    /// assert_eq!(enum_attribute.get_enum_value(), 16);
+    /// ```
+    pub fn get_enum_value(&self) -> u64 {
+        unsafe {
+            LLVMGetEnumAttributeValue(self.attribute)
+        }
+    }
+
+    /// Gets the key associated with a string `Attribute`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use std::ffi::CString;
+    ///
+    /// let context = Context::create();
+    /// let string_attribute = context.create_string_attribute("my_key", "my_val");
+    ///
+    /// assert_eq!(*string_attribute.get_string_kind_id(), *CString::new("my_key").unwrap());
+    /// ```
+    pub fn get_string_kind_id(&self) -> &CStr {
+        let mut length = 0;
+        let cstr_ptr = unsafe {
+            LLVMGetStringAttributeKind(self.attribute, &mut length)
+        };
+
+        unsafe {
+            CStr::from_ptr(cstr_ptr)
+        }
+    }
+
+    /// Gets the value associated with a string `Attribute`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use std::ffi::CString;
+    ///
+    /// let context = Context::create();
+    /// let string_attribute = context.create_string_attribute("my_key", "my_val");
+    ///
+    /// assert_eq!(*string_attribute.get_string_value(), *CString::new("my_val").unwrap());
+    /// ```
+    pub fn get_string_value(&self) -> &CStr {
+        let mut length = 0;
+        let cstr_ptr = unsafe {
+            LLVMGetStringAttributeValue(self.attribute, &mut length)
+        };
+
+        unsafe {
+            CStr::from_ptr(cstr_ptr)
+        }
+    }
+}
+
+/// An `AttributeLoc` determines where on a function an attribute is assigned to.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AttributeLoc {
+    /// Assign to the `FunctionValue`'s return type.
+    Return,
+    /// Assign to one of the `FunctionValue`'s params (0-indexed).
+    Param(u32),
+    /// Assign to the `FunctionValue` itself.
+    Function,
+}
+
+impl AttributeLoc {
+    pub(crate) fn get_index(&self) -> u32 {
+        match *self {
+            AttributeLoc::Return => 0,
+            AttributeLoc::Param(index) => {
+                assert!(index <= u32::max_value() - 2, "Param index must be <= u32::max_value() - 2");
+
+                index + 1
+            },
+            AttributeLoc::Function => u32::max_value(),
+        }
+    }
+}
@@ -1,10 +1,12 @@
 //! A `BasicBlock` is a container of instructions.
 
-use llvm_sys::core::{LLVMGetBasicBlockParent, LLVMGetBasicBlockTerminator, LLVMGetNextBasicBlock, LLVMInsertBasicBlock, LLVMIsABasicBlock, LLVMIsConstant, LLVMMoveBasicBlockAfter, LLVMMoveBasicBlockBefore, LLVMPrintTypeToString, LLVMPrintValueToString, LLVMTypeOf, LLVMDeleteBasicBlock, LLVMGetPreviousBasicBlock, LLVMRemoveBasicBlockFromParent, LLVMGetFirstInstruction, LLVMGetLastInstruction, LLVMGetTypeContext, LLVMBasicBlockAsValue};
+use llvm_sys::core::{LLVMGetBasicBlockParent, LLVMGetBasicBlockTerminator, LLVMGetNextBasicBlock, LLVMInsertBasicBlock, LLVMIsABasicBlock, LLVMIsConstant, LLVMMoveBasicBlockAfter, LLVMMoveBasicBlockBefore, LLVMPrintTypeToString, LLVMPrintValueToString, LLVMTypeOf, LLVMDeleteBasicBlock, LLVMGetPreviousBasicBlock, LLVMRemoveBasicBlockFromParent, LLVMGetFirstInstruction, LLVMGetLastInstruction, LLVMGetTypeContext, LLVMBasicBlockAsValue, LLVMGetFirstUse, LLVMGetNextUse, LLVMGetUser, LLVMIsATerminatorInst, LLVMGetValueName, LLVMSetValueName};
 use llvm_sys::prelude::{LLVMValueRef, LLVMBasicBlockRef};
 
+use builder::Builder;
 use context::{Context, ContextRef};
-use values::{FunctionValue, InstructionValue};
+use support::LLVMString;
+use values::{AnyValue, BasicValue, FunctionValue, InstructionValue, InstructionOpcode};
 
 use std::fmt;
 use std::ffi::{CStr, CString};
@@ -328,6 +330,150 @@ impl BasicBlock {
         Some(InstructionValue::new(value))
     }
 
+    /// Gets the `BasicBlock`s this one's terminator may transfer control to, in the order
+    /// given by `InstructionValue::get_successor`. A `BasicBlock` appearing several times,
+    /// such as a `switch` destination shared by multiple cases, is only returned once.
+    /// Returns an empty `Vec` if there is no terminator.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let builder = context.create_builder();
+    /// let module = context.create_module("my_module");
+    /// let void_type = context.void_type();
+    /// let bool_type = context.bool_type();
+    /// let fn_type = void_type.fn_type(&[&bool_type], false);
+    /// let function = module.add_function("my_fn", &fn_type, None);
+    /// let entry = context.append_basic_block(&function, "entry");
+    /// let then_block = context.append_basic_block(&function, "then");
+    /// let else_block = context.append_basic_block(&function, "else");
+    /// let condition = function.get_first_param().unwrap().into_int_value();
+    ///
+    /// builder.position_at_end(&entry);
+    /// builder.build_conditional_branch(&condition, &then_block, &else_block);
+    ///
+    /// assert_eq!(entry.successors(), vec![then_block, else_block]);
+    /// ```
+    pub fn successors(&self) -> Vec<BasicBlock> {
+        let terminator = match self.get_terminator() {
+            Some(terminator) => terminator,
+            None => return Vec::new(),
+        };
+
+        let mut successors: Vec<BasicBlock> = Vec::new();
+
+        for index in 0..terminator.count_successors() {
+            let successor = terminator.get_successor(index).expect("Successor index should be in bounds");
+
+            if !successors.contains(&successor) {
+                successors.push(successor);
+            }
+        }
+
+        successors
+    }
+
+    /// Gets the `BasicBlock`s whose terminators may transfer control to this one, found by
+    /// walking its uses. Each predecessor is only returned once, in no particular order.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let builder = context.create_builder();
+    /// let module = context.create_module("my_module");
+    /// let void_type = context.void_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    /// let function = module.add_function("my_fn", &fn_type, None);
+    /// let entry = context.append_basic_block(&function, "entry");
+    /// let exit = context.append_basic_block(&function, "exit");
+    ///
+    /// builder.position_at_end(&entry);
+    /// builder.build_unconditional_branch(&exit);
+    ///
+    /// assert_eq!(exit.predecessors(), vec![entry]);
+    /// assert!(function.get_entry_basic_block().unwrap().predecessors().is_empty());
+    /// ```
+    pub fn predecessors(&self) -> Vec<BasicBlock> {
+        let mut use_ = unsafe {
+            LLVMGetFirstUse(LLVMBasicBlockAsValue(self.basic_block))
+        };
+        let mut predecessors: Vec<BasicBlock> = Vec::new();
+
+        while !use_.is_null() {
+            let user = unsafe {
+                LLVMGetUser(use_)
+            };
+
+            // Blocks may also be used by non terminators, such as blockaddress constants
+            let is_terminator = unsafe {
+                !LLVMIsATerminatorInst(user).is_null()
+            };
+
+            if is_terminator {
+                let predecessor = InstructionValue::new(user).get_parent();
+
+                if let Some(predecessor) = predecessor {
+                    if !predecessors.contains(&predecessor) {
+                        predecessors.push(predecessor);
+                    }
+                }
+            }
+
+            use_ = unsafe {
+                LLVMGetNextUse(use_)
+            };
+        }
+
+        predecessors
+    }
+
+    /// Creates a double-ended iterator over the `InstructionValue`s in this `BasicBlock`.
+    ///
+    /// The iterator moves past each instruction before yielding it, so the instruction
+    /// just yielded may be erased or moved elsewhere without disrupting the iteration.
+    /// This only covers that one instruction: both ends of the iterator hold on to the
+    /// next instruction they will yield, which starts out as this block's first and last
+    /// instruction. Erasing either of those, or any other instruction not yet yielded,
+    /// leaves the iterator pointing at freed memory.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::values::InstructionOpcode;
+    ///
+    /// let context = Context::create();
+    /// let builder = context.create_builder();
+    /// let module = context.create_module("my_module");
+    /// let void_type = context.void_type();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    /// let function = module.add_function("do_nothing", &fn_type, None);
+    /// let basic_block = context.append_basic_block(&function, "entry");
+    ///
+    /// builder.position_at_end(&basic_block);
+    /// builder.build_alloca(&i32_type, "unused");
+    /// builder.build_return(None);
+    ///
+    /// for instruction in basic_block.instructions() {
+    ///     if instruction.get_opcode() == InstructionOpcode::Alloca {
+    ///         instruction.erase().unwrap();
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(basic_block.instructions().count(), 1);
+    /// assert_eq!(basic_block.instructions().next_back().unwrap().get_opcode(), InstructionOpcode::Return);
+    /// ```
+    pub fn instructions(&self) -> InstructionIter {
+        InstructionIter {
+            next: self.get_first_instruction(),
+            next_back: self.get_last_instruction(),
+        }
+    }
+
     /// Removes this `BasicBlock` from its parent `FunctionValue`. Does nothing if it has no parent.
     ///
     /// # Example
@@ -362,7 +508,16 @@ impl BasicBlock {
         }
     }
 
-    /// Removes this `BasicBlock` completely from memory. This is unsafe because you could easily have other references to the same `BasicBlock`.
+    /// Removes this `BasicBlock` from its parent `FunctionValue` and deletes it, along with all
+    /// of its instructions. Once deleted, neither this `BasicBlock` nor any copies of it or its
+    /// instructions may be used again.
+    ///
+    /// Returns an error, leaving the `BasicBlock` untouched, if a branch or any other value
+    /// still refers to it, if a phi node in one of its successors still lists it as an
+    /// incoming block, if one of its instructions is still used outside of it, or if it
+    /// has no parent `FunctionValue`. Phi nodes don't count as uses of their incoming blocks
+    /// in LLVM, and the C API can't remove incoming entries, so such phis have to be rebuilt
+    /// without this block before it can be deleted.
     ///
     /// # Example
     /// ```no_run
@@ -377,16 +532,207 @@ impl BasicBlock {
     /// let function = module.add_function("do_nothing", &fn_type, None);
     /// let basic_block = context.append_basic_block(&function, "entry");
     ///
-    /// unsafe {
-    ///     basic_block.delete();
-    /// }
+    /// assert!(basic_block.delete().is_ok());
     /// assert!(function.get_basic_blocks().is_empty());
     /// ```
-    // REVIEW: Could potentially be unsafe if there are existing references. Might need a global ref counter
-    pub unsafe fn delete(self) {
-        // unsafe {
-        LLVMDeleteBasicBlock(self.basic_block)
-        // }
+    pub fn delete(self) -> Result<(), LLVMString> {
+        // LLVMDeleteBasicBlock unlinks the block from its parent, which is UB for an orphan
+        if self.get_parent().is_none() {
+            return Err(LLVMString::create("Cannot delete a basic block which has no parent function"));
+        }
+
+        let first_use = unsafe {
+            LLVMGetFirstUse(LLVMBasicBlockAsValue(self.basic_block))
+        };
+
+        if !first_use.is_null() {
+            return Err(LLVMString::create("Cannot delete a basic block which is still referred to, such as by a branch"));
+        }
+
+        for successor in self.successors() {
+            let is_incoming = successor.instructions()
+                .take_while(|instruction| instruction.get_opcode() == InstructionOpcode::Phi)
+                .filter_map(|instruction| instruction.as_phi())
+                .any(|phi| {
+                    (0..phi.count_incoming())
+                        .filter_map(|index| phi.get_incoming(index))
+                        .any(|(_, basic_block)| basic_block == self)
+                });
+
+            if is_incoming {
+                return Err(LLVMString::create("Cannot delete a basic block which phi nodes in its successors still list as an incoming block"));
+            }
+        }
+
+        for instruction in self.instructions() {
+            for use_ in instruction.uses() {
+                let is_local = use_.get_user()
+                    .and_then(|user| user.left())
+                    .and_then(|user| user.get_parent())
+                    .map_or(false, |parent| parent == self);
+
+                if !is_local {
+                    return Err(LLVMString::create("Cannot delete a basic block whose instructions are still used outside of it"));
+                }
+            }
+        }
+
+        unsafe {
+            LLVMDeleteBasicBlock(self.basic_block)
+        }
+
+        Ok(())
+    }
+
+    /// Splits this `BasicBlock` in two at `instruction`, which must belong to it. The new
+    /// `BasicBlock` is placed directly after this one and receives `instruction` along with
+    /// every instruction following it, while this `BasicBlock` gets an unconditional branch
+    /// to the new one. Phi nodes in the successors are updated to refer to the new `BasicBlock`.
+    ///
+    /// As the C API can't change a phi's incoming blocks, every successor phi which lists this
+    /// `BasicBlock` is replaced by a new phi with the same name, type and incoming values,
+    /// and the old one is erased. Any `PhiValue` or `InstructionValue` previously obtained for
+    /// those phis must not be used again; fetch the new ones from the successor instead. The
+    /// replacements don't carry over any metadata or fast math flags of the originals.
+    ///
+    /// Returns an error if `instruction` is in another `BasicBlock` or is a phi node, or if
+    /// this `BasicBlock` has no parent `FunctionValue`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::values::InstructionOpcode;
+    ///
+    /// let context = Context::create();
+    /// let builder = context.create_builder();
+    /// let module = context.create_module("my_module");
+    /// let void_type = context.void_type();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    /// let function = module.add_function("do_nothing", &fn_type, None);
+    /// let basic_block = context.append_basic_block(&function, "entry");
+    ///
+    /// builder.position_at_end(&basic_block);
+    ///
+    /// let alloca = builder.build_alloca(&i32_type, "alloca");
+    /// let ret = builder.build_return(None);
+    ///
+    /// let tail = basic_block.split_at(&ret, "tail").unwrap();
+    ///
+    /// assert_eq!(basic_block.get_next_basic_block().unwrap(), tail);
+    /// assert_eq!(basic_block.get_first_instruction(), alloca.as_instruction());
+    /// assert_eq!(basic_block.get_terminator().unwrap().get_opcode(), InstructionOpcode::Br);
+    /// assert_eq!(tail.get_first_instruction().unwrap(), ret);
+    /// ```
+    pub fn split_at(&self, instruction: &InstructionValue, name: &str) -> Result<BasicBlock, LLVMString> {
+        if instruction.get_parent().map_or(true, |parent| parent != *self) {
+            return Err(LLVMString::create("Cannot split a basic block at an instruction it does not contain"));
+        }
+
+        if instruction.get_opcode() == InstructionOpcode::Phi {
+            return Err(LLVMString::create("Cannot split a basic block at a phi node"));
+        }
+
+        let function = match self.get_parent() {
+            Some(function) => function,
+            None => return Err(LLVMString::create("Cannot split a basic block which has no parent function")),
+        };
+
+        let new_basic_block = function.append_basic_block(name);
+
+        new_basic_block.move_after(self);
+
+        let mut tail = Vec::new();
+        let mut current_instruction = Some(*instruction);
+
+        while let Some(tail_instruction) = current_instruction {
+            tail.push(tail_instruction);
+            current_instruction = tail_instruction.get_next_instruction();
+        }
+
+        let builder = Builder::create();
+
+        builder.position_at_end(&new_basic_block);
+
+        for tail_instruction in tail {
+            let detached = tail_instruction.detach().expect("Instruction should be in this basic block");
+
+            builder.insert_detached_instruction(detached);
+        }
+
+        builder.position_at_end(self);
+        builder.build_unconditional_branch(&new_basic_block);
+
+        // Control now reaches the successors from the new block rather than this one.
+        // The C API can't retarget a phi's incoming block, so affected phis are rebuilt
+        for successor in new_basic_block.successors() {
+            let phis: Vec<_> = successor.instructions()
+                .take_while(|instruction| instruction.get_opcode() == InstructionOpcode::Phi)
+                .filter_map(|instruction| instruction.as_phi())
+                .collect();
+
+            for phi in phis {
+                let incoming: Vec<_> = (0..phi.count_incoming())
+                    .filter_map(|index| phi.get_incoming(index))
+                    .collect();
+
+                if !incoming.iter().any(|&(_, ref basic_block)| basic_block == self) {
+                    continue;
+                }
+
+                builder.position_before(&phi.as_instruction());
+
+                let phi_name = phi.get_name().to_string_lossy().into_owned();
+                let new_phi = builder.build_phi(&phi.as_basic_value().get_type(), "");
+
+                for &(ref value, ref basic_block) in &incoming {
+                    let basic_block = if basic_block == self { &new_basic_block } else { basic_block };
+
+                    new_phi.add_incoming(&[(value as &BasicValue, basic_block)]);
+                }
+
+                phi.replace_all_uses_with(&new_phi);
+                phi.as_instruction().erase().expect("Phi should have no uses after being replaced");
+                new_phi.set_name(&phi_name);
+            }
+        }
+
+        Ok(new_basic_block)
+    }
+
+    /// Gets the name of this `BasicBlock`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use std::ffi::CString;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let void_type = context.void_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    /// let function = module.add_function("do_nothing", &fn_type, None);
+    /// let basic_block = context.append_basic_block(&function, "entry");
+    ///
+    /// assert_eq!(*basic_block.get_name(), *CString::new("entry").unwrap());
+    ///
+    /// basic_block.set_name("start");
+    ///
+    /// assert_eq!(*basic_block.get_name(), *CString::new("start").unwrap());
+    /// ```
+    pub fn get_name(&self) -> &CStr {
+        unsafe {
+            CStr::from_ptr(LLVMGetValueName(LLVMBasicBlockAsValue(self.basic_block)))
+        }
+    }
+
+    /// Sets the name of this `BasicBlock`. LLVM may add a suffix to keep it unique within its `FunctionValue`.
+    pub fn set_name(&self, name: &str) {
+        let c_string = CString::new(name).expect("Conversion to CString failed unexpectedly");
+
+        unsafe {
+            LLVMSetValueName(LLVMBasicBlockAsValue(self.basic_block), c_string.as_ptr())
+        }
     }
 
     /// Obtains the `ContextRef` this `BasicBlock` belongs to.
@@ -436,3 +782,49 @@ impl fmt::Debug for BasicBlock {
             .finish()
     }
 }
+
+/// A double-ended iterator over the `InstructionValue`s in a `BasicBlock`. See `BasicBlock::instructions`.
+#[derive(Debug)]
+pub struct InstructionIter {
+    next: Option<InstructionValue>,
+    next_back: Option<InstructionValue>,
+}
+
+impl Iterator for InstructionIter {
+    type Item = InstructionValue;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let instruction = match self.next {
+            Some(instruction) => instruction,
+            None => return None,
+        };
+
+        // Both ends have met, so there is nothing left to yield
+        if Some(instruction) == self.next_back {
+            self.next = None;
+            self.next_back = None;
+        } else {
+            self.next = instruction.get_next_instruction();
+        }
+
+        Some(instruction)
+    }
+}
+
+impl DoubleEndedIterator for InstructionIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let instruction = match self.next_back {
+            Some(instruction) => instruction,
+            None => return None,
+        };
+
+        if Some(instruction) == self.next {
+            self.next = None;
+            self.next_back = None;
+        } else {
+            self.next_back = instruction.get_previous_instruction();
+        }
+
+        Some(instruction)
+    }
+}
@@ -1,21 +1,134 @@
-use llvm_sys::core::{LLVMAppendBasicBlockInContext, LLVMContextCreate, LLVMContextDispose, LLVMCreateBuilderInContext, LLVMDoubleTypeInContext, LLVMFloatTypeInContext, LLVMFP128TypeInContext, LLVMInsertBasicBlockInContext, LLVMInt16TypeInContext, LLVMInt1TypeInContext, LLVMInt32TypeInContext, LLVMInt64TypeInContext, LLVMInt8TypeInContext, LLVMIntTypeInContext, LLVMModuleCreateWithNameInContext, LLVMStructCreateNamed, LLVMStructTypeInContext, LLVMVoidTypeInContext, LLVMHalfTypeInContext, LLVMGetGlobalContext, LLVMPPCFP128TypeInContext, LLVMConstStructInContext, LLVMMDNodeInContext, LLVMMDStringInContext, LLVMGetMDKindIDInContext};
+use libc::c_char;
+use llvm_sys::core::{LLVMAppendBasicBlockInContext, LLVMContextCreate, LLVMContextDispose, LLVMCreateBuilderInContext, LLVMDoubleTypeInContext, LLVMFloatTypeInContext, LLVMFP128TypeInContext, LLVMInsertBasicBlockInContext, LLVMInt16TypeInContext, LLVMInt1TypeInContext, LLVMInt32TypeInContext, LLVMInt64TypeInContext, LLVMInt8TypeInContext, LLVMIntTypeInContext, LLVMModuleCreateWithNameInContext, LLVMStructCreateNamed, LLVMStructTypeInContext, LLVMVoidTypeInContext, LLVMHalfTypeInContext, LLVMGetGlobalContext, LLVMPPCFP128TypeInContext, LLVMConstStructInContext, LLVMMDNodeInContext, LLVMMDStringInContext, LLVMGetMDKindIDInContext, LLVMConstStringInContext};
 use llvm_sys::prelude::{LLVMContextRef, LLVMTypeRef, LLVMValueRef};
 use llvm_sys::ir_reader::LLVMParseIRInContext;
 
+#[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+use attributes::Attribute;
 use basic_block::BasicBlock;
 use builder::Builder;
 use memory_buffer::MemoryBuffer;
 use module::Module;
 use support::LLVMString;
 use types::{BasicType, FloatType, IntType, StructType, VoidType};
-use values::{AsValueRef, BasicValue, FunctionValue, StructValue, MetadataValue};
+use values::{ArrayValue, AsValueRef, BasicValue, FunctionValue, StructValue, MetadataValue};
 
+use std::error::Error;
 use std::ffi::{CStr, CString};
+use std::fmt::{self, Display, Formatter};
 use std::mem::forget;
 use std::ops::Deref;
 use std::ptr;
 use std::rc::Rc;
 
+/// An error produced when IR fails to parse, pointing at the location of the problem.
+#[derive(Debug, PartialEq, Eq)]
+pub struct IrParseError {
+    filename: String,
+    line: Option<u32>,
+    column: Option<u32>,
+    source_line: Option<String>,
+    message: String,
+    diagnostic: String,
+}
+
+impl IrParseError {
+    // LLVM formats parse diagnostics as:
+    // <filename>:<line>:<column>: error: <message>
+    // <source line>
+    // <caret marker>
+    // where the line and column (both 1-based) as well as the source lines may be absent
+    pub(crate) fn new(llvm_string: LLVMString) -> Self {
+        let diagnostic = llvm_string.to_string();
+
+        let (filename, line, column, source_line, message) = {
+            let mut lines = diagnostic.lines();
+            let header = lines.next().unwrap_or("");
+
+            let (location, message) = match header.find(": error: ") {
+                Some(index) => (&header[..index], &header[index + ": error: ".len()..]),
+                None => match header.find("error: ") {
+                    Some(index) => (header[..index].trim_right_matches(": "), &header[index + "error: ".len()..]),
+                    None => ("", header),
+                },
+            };
+
+            // Filenames (ie windows paths) may themselves contain colons, so parse from the right
+            let mut parts = location.rsplitn(3, ':');
+            let last = parts.next().and_then(|part| part.parse::<u32>().ok());
+            let second_last = parts.next().and_then(|part| part.parse::<u32>().ok());
+
+            let (filename, line, column) = match (second_last, last) {
+                (Some(line), Some(column)) => (parts.next().unwrap_or(""), Some(line), Some(column)),
+                (None, Some(line)) => (location.rsplitn(2, ':').nth(1).unwrap_or(""), Some(line), None),
+                _ => (location, None, None),
+            };
+
+            let source_line = match column {
+                Some(_) => lines.next().map(|line| line.to_string()),
+                None => None,
+            };
+
+            (filename.to_string(), line, column, source_line, message.to_string())
+        };
+
+        IrParseError {
+            filename,
+            line,
+            column,
+            source_line,
+            message,
+            diagnostic,
+        }
+    }
+
+    /// Gets the name of the `MemoryBuffer` which failed to parse.
+    pub fn get_filename(&self) -> &str {
+        &self.filename
+    }
+
+    /// Gets the 1-based line on which the error occurred, if known.
+    pub fn get_line(&self) -> Option<u32> {
+        self.line
+    }
+
+    /// Gets the 1-based column at which the error occurred, if known.
+    pub fn get_column(&self) -> Option<u32> {
+        self.column
+    }
+
+    /// Gets the full line of source which contains the error, if known.
+    pub fn get_source_line(&self) -> Option<&str> {
+        self.source_line.as_ref().map(|line| line.as_str())
+    }
+
+    /// Gets the description of the error, without any location information.
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+
+    /// Gets the complete diagnostic as it was reported by LLVM.
+    pub fn get_diagnostic(&self) -> &str {
+        &self.diagnostic
+    }
+}
+
+impl Error for IrParseError {
+    fn description(&self) -> &str {
+        self.get_message()
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        None
+    }
+}
+
+impl Display for IrParseError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "IrParseError({})", self.diagnostic.trim_right())
+    }
+}
+
 // From Docs: A single context is not thread safe.
 // However, different contexts can execute on different threads simultaneously.
 #[derive(Debug, PartialEq, Eq)]
@@ -104,11 +217,28 @@ impl Context {
         Module::new(module, Some(&self))
     }
 
+    /// Parses textual (or bitcode) IR from a `MemoryBuffer` into a new `Module`.
+    ///
+    /// On failure, the returned `IrParseError` points at the location of the problem. Its filename
+    /// is the name given to the `MemoryBuffer` when it was created.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::memory_buffer::MemoryBuffer;
+    ///
+    /// let context = Context::create();
+    /// let memory_buffer = MemoryBuffer::create_from_memory_range("define void @f() {\n  ret i32\n}\n", "my_ir");
+    /// let err = context.create_module_from_ir(memory_buffer).unwrap_err();
+    ///
+    /// assert_eq!(err.get_filename(), "my_ir");
+    /// assert_eq!(err.get_line(), Some(2));
+    /// ```
     // REVIEW: I haven't yet been able to find docs or other wrappers that confirm, but my suspicion
     // is that the method needs to take ownership of the MemoryBuffer... otherwise I see what looks like
     // a double free in valgrind when the MemoryBuffer drops so we are `forget`ting MemoryBuffer here
     // for now until we can confirm this is the correct thing to do
-    pub fn create_module_from_ir(&self, memory_buffer: MemoryBuffer) -> Result<Module, LLVMString> {
+    pub fn create_module_from_ir(&self, memory_buffer: MemoryBuffer) -> Result<Module, IrParseError> {
         let mut module = ptr::null_mut();
         let mut err_str = ptr::null_mut();
 
@@ -122,7 +252,7 @@ impl Context {
             return Ok(Module::new(module, Some(&self)));
         }
 
-        Err(LLVMString::new(err_str))
+        Err(IrParseError::new(LLVMString::new(err_str)))
     }
 
     pub fn void_type(&self) -> VoidType {
@@ -295,6 +425,78 @@ impl Context {
         StructValue::new(value)
     }
 
+    /// Creates a constant `[N x i8]` array holding `bytes`, optionally followed by a null
+    /// terminator. The bytes are copied into LLVM in one go, rather than creating a value per byte.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let string = context.const_string(b"my_string", true);
+    ///
+    /// assert_eq!(string.get_type().len(), 10);
+    /// assert_eq!(string.as_bytes(), Some(&b"my_string\0"[..]));
+    /// ```
+    pub fn const_string(&self, bytes: &[u8], null_terminated: bool) -> ArrayValue {
+        let value = unsafe {
+            LLVMConstStringInContext(*self.context, bytes.as_ptr() as *const c_char, bytes.len() as u32, !null_terminated as i32)
+        };
+
+        ArrayValue::new(value)
+    }
+
+    /// Creates an enum `Attribute` in this `Context`. See `Attribute::get_named_enum_kind_id`
+    /// for looking up the kind id of a builtin attribute such as "sret" or "noinline".
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::attributes::Attribute;
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let kind_id = Attribute::get_named_enum_kind_id("align");
+    /// let enum_attribute = context.create_enum_attribute(kind_id, 8);
+    ///
+    /// assert!(enum_attribute.is_enum());
+    /// assert_eq!(enum_attribute.get_enum_kind_id(), kind_id);
+    /// assert_eq!(enum_attribute.get_enum_value(), 8);
+    /// ```
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+    pub fn create_enum_attribute(&self, kind_id: u32, val: u64) -> Attribute {
+        use llvm_sys::core::LLVMCreateEnumAttribute;
+
+        let attribute = unsafe {
+            LLVMCreateEnumAttribute(*self.context, kind_id, val)
+        };
+
+        Attribute::new(attribute)
+    }
+
+    /// Creates a string `Attribute` in this `Context`, such as `"target-cpu"="x86-64"`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let string_attribute = context.create_string_attribute("target-cpu", "x86-64");
+    ///
+    /// assert!(string_attribute.is_string());
+    /// ```
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+    pub fn create_string_attribute(&self, key: &str, val: &str) -> Attribute {
+        use llvm_sys::core::LLVMCreateStringAttribute;
+
+        let attribute = unsafe {
+            LLVMCreateStringAttribute(*self.context, key.as_ptr() as *const c_char, key.len() as u32, val.as_ptr() as *const c_char, val.len() as u32)
+        };
+
+        Attribute::new(attribute)
+    }
+
     // REVIEW: Maybe more helpful to beginners to call this metadata_tuple?
     // REVIEW: Seems to be unassgned to anything
     pub fn metadata_node(&self, values: &[&BasicValue]) -> MetadataValue {
@@ -11,6 +11,9 @@ extern crate enum_methods;
 extern crate libc;
 extern crate llvm_sys;
 
+pub mod analysis;
+#[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+pub mod attributes;
 #[deny(missing_docs)]
 pub mod basic_block;
 pub mod builder;
@@ -19,6 +22,7 @@ pub mod data_layout;
 pub mod execution_engine;
 pub mod memory_buffer;
 pub mod module;
+mod module_diff;
 pub mod object_file;
 pub mod passes;
 pub mod support;
@@ -63,9 +67,9 @@ impl From<u32> for AddressSpace {
         match val {
             0 => AddressSpace::Generic,
             1 => AddressSpace::Global,
-            2 => AddressSpace::Shared,
-            3 => AddressSpace::Const,
-            4 => AddressSpace::Local,
+            3 => AddressSpace::Shared,
+            4 => AddressSpace::Const,
+            5 => AddressSpace::Local,
             _ => unreachable!("Invalid value for AddressSpace"),
         }
     }
@@ -87,6 +91,21 @@ pub enum IntPredicate {
 }
 
 impl IntPredicate {
+    pub(crate) fn new(predicate: LLVMIntPredicate) -> Self {
+        match predicate {
+            LLVMIntPredicate::LLVMIntEQ => IntPredicate::EQ,
+            LLVMIntPredicate::LLVMIntNE => IntPredicate::NE,
+            LLVMIntPredicate::LLVMIntUGT => IntPredicate::UGT,
+            LLVMIntPredicate::LLVMIntUGE => IntPredicate::UGE,
+            LLVMIntPredicate::LLVMIntULT => IntPredicate::ULT,
+            LLVMIntPredicate::LLVMIntULE => IntPredicate::ULE,
+            LLVMIntPredicate::LLVMIntSGT => IntPredicate::SGT,
+            LLVMIntPredicate::LLVMIntSGE => IntPredicate::SGE,
+            LLVMIntPredicate::LLVMIntSLT => IntPredicate::SLT,
+            LLVMIntPredicate::LLVMIntSLE => IntPredicate::SLE,
+        }
+    }
+
     pub(crate) fn as_llvm_predicate(&self) -> LLVMIntPredicate {
         match *self {
             IntPredicate::EQ => LLVMIntPredicate::LLVMIntEQ,
@@ -142,6 +161,27 @@ pub enum FloatPredicate {
 }
 
 impl FloatPredicate {
+    pub(crate) fn new(predicate: LLVMRealPredicate) -> Self {
+        match predicate {
+            LLVMRealPredicate::LLVMRealPredicateFalse => FloatPredicate::PredicateFalse,
+            LLVMRealPredicate::LLVMRealOEQ => FloatPredicate::OEQ,
+            LLVMRealPredicate::LLVMRealOGT => FloatPredicate::OGT,
+            LLVMRealPredicate::LLVMRealOGE => FloatPredicate::OGE,
+            LLVMRealPredicate::LLVMRealOLT => FloatPredicate::OLT,
+            LLVMRealPredicate::LLVMRealOLE => FloatPredicate::OLE,
+            LLVMRealPredicate::LLVMRealONE => FloatPredicate::ONE,
+            LLVMRealPredicate::LLVMRealORD => FloatPredicate::ORD,
+            LLVMRealPredicate::LLVMRealUNO => FloatPredicate::UNO,
+            LLVMRealPredicate::LLVMRealUEQ => FloatPredicate::UEQ,
+            LLVMRealPredicate::LLVMRealUGT => FloatPredicate::UGT,
+            LLVMRealPredicate::LLVMRealUGE => FloatPredicate::UGE,
+            LLVMRealPredicate::LLVMRealULT => FloatPredicate::ULT,
+            LLVMRealPredicate::LLVMRealULE => FloatPredicate::ULE,
+            LLVMRealPredicate::LLVMRealUNE => FloatPredicate::UNE,
+            LLVMRealPredicate::LLVMRealPredicateTrue => FloatPredicate::PredicateTrue,
+        }
+    }
+
     pub(crate) fn as_llvm_predicate(&self) -> LLVMRealPredicate {
         match *self {
             FloatPredicate::PredicateFalse => LLVMRealPredicate::LLVMRealPredicateFalse,
@@ -27,12 +27,12 @@ impl MemoryBuffer {
 
     pub fn create_from_file(path: &Path) -> Result<Self, LLVMString> {
         let path = path.to_str().expect("Did not find a valid Unicode path string");
+        let c_string = CString::new(path).expect("Conversion to CString failed unexpectedly");
         let mut memory_buffer = ptr::null_mut();
         let mut err_string = unsafe { zeroed() };
 
         let return_code = unsafe {
-            // REVIEW: Unclear why this expects *const i8 instead of *const u8
-            LLVMCreateMemoryBufferWithContentsOfFile(path.as_ptr() as *const i8, &mut memory_buffer, &mut err_string)
+            LLVMCreateMemoryBufferWithContentsOfFile(c_string.as_ptr(), &mut memory_buffer, &mut err_string)
         };
 
         // TODO: Verify 1 is error code (LLVM can be inconsistent)
@@ -112,11 +112,19 @@ impl MemoryBuffer {
         }
     }
 
-    pub fn create_object_file(&self) -> ObjectFile {
+    /// Parses this `MemoryBuffer` as an object file. The `ObjectFile` takes ownership of the
+    /// underlying buffer, which LLVM frees even if parsing fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer does not contain an object file LLVM can read.
+    pub fn create_object_file(self) -> ObjectFile {
         let object_file = unsafe {
             LLVMCreateObjectFile(self.memory_buffer)
         };
 
+        forget(self);
+
         ObjectFile::new(object_file)
     }
 }
@@ -1,15 +1,19 @@
 use llvm_sys::analysis::{LLVMVerifyModule, LLVMVerifierFailureAction};
+use llvm_sys::bit_reader::{LLVMParseBitcodeInContext, LLVMGetBitcodeModuleInContext};
 use llvm_sys::bit_writer::{LLVMWriteBitcodeToFile, LLVMWriteBitcodeToMemoryBuffer};
-use llvm_sys::core::{LLVMAddFunction, LLVMAddGlobal, LLVMDumpModule, LLVMGetNamedFunction, LLVMGetTypeByName, LLVMSetDataLayout, LLVMSetTarget, LLVMCloneModule, LLVMDisposeModule, LLVMGetTarget, LLVMModuleCreateWithName, LLVMGetModuleContext, LLVMGetFirstFunction, LLVMGetLastFunction, LLVMSetLinkage, LLVMAddGlobalInAddressSpace, LLVMPrintModuleToString, LLVMGetNamedMetadataNumOperands, LLVMAddNamedMetadataOperand, LLVMGetNamedMetadataOperands, LLVMGetFirstGlobal, LLVMGetLastGlobal, LLVMGetNamedGlobal, LLVMPrintModuleToFile, LLVMSetModuleInlineAsm};
+use llvm_sys::core::{LLVMAddFunction, LLVMAddGlobal, LLVMDumpModule, LLVMGetNamedFunction, LLVMGetTypeByName, LLVMSetDataLayout, LLVMSetTarget, LLVMCloneModule, LLVMDisposeModule, LLVMGetTarget, LLVMModuleCreateWithName, LLVMGetModuleContext, LLVMGetFirstFunction, LLVMGetLastFunction, LLVMSetLinkage, LLVMAddGlobalInAddressSpace, LLVMPrintModuleToString, LLVMGetNamedMetadataNumOperands, LLVMAddNamedMetadataOperand, LLVMGetNamedMetadataOperands, LLVMGetFirstGlobal, LLVMGetLastGlobal, LLVMGetNamedGlobal, LLVMPrintModuleToFile, LLVMSetModuleInlineAsm, LLVMGetNextFunction, LLVMGetPreviousFunction, LLVMGetNextGlobal, LLVMGetPreviousGlobal, LLVMAddAlias, LLVMPointerType, LLVMGetPointerAddressSpace, LLVMTypeOf, LLVMGetFirstUse, LLVMGetNextUse, LLVMGetUser, LLVMIsAGlobalAlias, LLVMIsAConstantExpr, LLVMGetGlobalParent, LLVMMDNodeInContext, LLVMConstIntGetZExtValue, LLVMConstPointerCast, LLVMConstStructInContext, LLVMGetInitializer, LLVMSetInitializer, LLVMIsAConstantArray, LLVMGetNumOperands, LLVMGetOperand, LLVMConstArray, LLVMReplaceAllUsesWith, LLVMConstBitCast};
 use llvm_sys::execution_engine::{LLVMCreateInterpreterForModule, LLVMCreateJITCompilerForModule, LLVMCreateExecutionEngineForModule};
-use llvm_sys::prelude::{LLVMValueRef, LLVMModuleRef};
+use llvm_sys::prelude::{LLVMValueRef, LLVMModuleRef, LLVMTypeRef};
 use llvm_sys::LLVMLinkage;
 
 use std::cell::{Cell, RefCell, Ref};
+use std::collections::{HashMap, HashSet};
 use std::ffi::{CString, CStr};
 use std::fs::File;
+use std::marker::PhantomData;
 use std::mem::{forget, uninitialized, zeroed};
 use std::path::Path;
+use std::ptr;
 use std::rc::Rc;
 use std::slice::from_raw_parts;
 
@@ -18,9 +22,11 @@ use context::{Context, ContextRef};
 use data_layout::DataLayout;
 use execution_engine::ExecutionEngine;
 use memory_buffer::MemoryBuffer;
+use module_diff::diff_modules;
 use support::LLVMString;
-use types::{AsTypeRef, BasicType, FunctionType, BasicTypeEnum};
-use values::{AsValueRef, FunctionValue, GlobalValue, MetadataValue};
+use targets::{FileType, TargetMachine};
+use types::{AnyType, AsTypeRef, BasicType, FunctionType, BasicTypeEnum};
+use values::{AsValueRef, BasicValue, BasicMetadataValueEnum, FunctionValue, GlobalValue, InstructionOpcode, MetadataValue, PointerValue};
 
 // REVIEW: Maybe this should go into it's own module?
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
@@ -67,7 +73,7 @@ impl Linkage {
         }
     }
 
-    fn as_llvm_linkage(&self) -> LLVMLinkage {
+    pub(crate) fn as_llvm_linkage(&self) -> LLVMLinkage {
         match *self {
             Linkage::AppendingLinkage => LLVMLinkage::LLVMAppendingLinkage,
             Linkage::AvailableExternallyLinkage => LLVMLinkage::LLVMAvailableExternallyLinkage,
@@ -90,6 +96,170 @@ impl Linkage {
     }
 }
 
+/// Defines how a module flag is merged when linking two `Module`s which both define it.
+///
+/// # Remarks
+/// See also: https://llvm.org/docs/LangRef.html#module-flags-metadata
+#[repr(u32)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum FlagBehavior {
+    /// Emits an error if the two values differ.
+    Error        = 1,
+    /// Emits a warning if the two values differ, and keeps the value from the destination `Module`.
+    Warning      = 2,
+    /// Requires that another flag, given as a metadata pair of its key and value, has that value after linking.
+    Require      = 3,
+    /// Uses the specified value, regardless of the value in the other `Module`.
+    Override     = 4,
+    /// Appends the two values, which must be metadata nodes.
+    Append       = 5,
+    /// Appends the two values, which must be metadata nodes, dropping duplicate elements.
+    AppendUnique = 6,
+}
+
+impl FlagBehavior {
+    fn new(behavior: u64) -> Option<Self> {
+        match behavior {
+            1 => Some(FlagBehavior::Error),
+            2 => Some(FlagBehavior::Warning),
+            3 => Some(FlagBehavior::Require),
+            4 => Some(FlagBehavior::Override),
+            5 => Some(FlagBehavior::Append),
+            6 => Some(FlagBehavior::AppendUnique),
+            _ => None,
+        }
+    }
+}
+
+/// Block and instruction counts for a single function. See `Module::statistics`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionStatistics {
+    name: String,
+    basic_blocks: u32,
+    instructions: u32,
+    opcodes: HashMap<InstructionOpcode, u32>,
+    machine_code_size: Option<u64>,
+}
+
+impl FunctionStatistics {
+    fn new(function: &FunctionValue) -> Self {
+        let mut statistics = FunctionStatistics {
+            name: function.get_name().to_string_lossy().into_owned(),
+            basic_blocks: 0,
+            instructions: 0,
+            opcodes: HashMap::new(),
+            machine_code_size: None,
+        };
+
+        for basic_block in function.get_basic_blocks() {
+            let mut instruction = basic_block.get_first_instruction();
+
+            statistics.basic_blocks += 1;
+
+            while let Some(current_instruction) = instruction {
+                statistics.instructions += 1;
+
+                *statistics.opcodes.entry(current_instruction.get_opcode()).or_insert(0) += 1;
+
+                instruction = current_instruction.get_next_instruction();
+            }
+        }
+
+        statistics
+    }
+
+    /// Gets the name of the function these statistics describe.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Counts the basic blocks in the function. This is zero for declarations.
+    pub fn count_basic_blocks(&self) -> u32 {
+        self.basic_blocks
+    }
+
+    /// Counts all instructions in the function.
+    pub fn count_instructions(&self) -> u32 {
+        self.instructions
+    }
+
+    /// Counts the instructions in the function with the given `InstructionOpcode`.
+    pub fn count_opcode(&self, opcode: InstructionOpcode) -> u32 {
+        self.opcodes.get(&opcode).cloned().unwrap_or(0)
+    }
+
+    /// Gets the number of instructions in the function for each `InstructionOpcode` that occurs in it.
+    pub fn get_opcode_counts(&self) -> &HashMap<InstructionOpcode, u32> {
+        &self.opcodes
+    }
+
+    /// Counts the `call` and `invoke` instructions in the function.
+    pub fn count_calls(&self) -> u32 {
+        self.count_opcode(InstructionOpcode::Call) + self.count_opcode(InstructionOpcode::Invoke)
+    }
+
+    /// Counts the `alloca` instructions in the function.
+    pub fn count_allocas(&self) -> u32 {
+        self.count_opcode(InstructionOpcode::Alloca)
+    }
+
+    /// Counts the `phi` instructions in the function.
+    pub fn count_phis(&self) -> u32 {
+        self.count_opcode(InstructionOpcode::Phi)
+    }
+
+    /// Gets the size in bytes of the machine code emitted for the function. This is only
+    /// available from `Module::statistics_with_machine_code`, and only for functions with
+    /// a symbol in the emitted object file.
+    pub fn get_machine_code_size(&self) -> Option<u64> {
+        self.machine_code_size
+    }
+}
+
+/// Function and global counts for a `Module`. See `Module::statistics`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleStatistics {
+    functions: Vec<FunctionStatistics>,
+    globals: u32,
+}
+
+impl ModuleStatistics {
+    /// Gets the statistics of every function in the `Module`, in order.
+    pub fn get_functions(&self) -> &[FunctionStatistics] {
+        &self.functions
+    }
+
+    /// Gets the statistics of the function called `name`, if it exists.
+    pub fn get_function(&self, name: &str) -> Option<&FunctionStatistics> {
+        self.functions.iter().find(|function| function.name == name)
+    }
+
+    /// Counts the functions in the `Module`, including declarations.
+    pub fn count_functions(&self) -> u32 {
+        self.functions.len() as u32
+    }
+
+    /// Counts the global variables in the `Module`, including declarations.
+    pub fn count_globals(&self) -> u32 {
+        self.globals
+    }
+
+    /// Counts the basic blocks across all functions in the `Module`.
+    pub fn count_basic_blocks(&self) -> u32 {
+        self.functions.iter().map(|function| function.basic_blocks).sum()
+    }
+
+    /// Counts the instructions across all functions in the `Module`.
+    pub fn count_instructions(&self) -> u32 {
+        self.functions.iter().map(|function| function.instructions).sum()
+    }
+
+    /// Gets the total size in bytes of the machine code emitted for the `Module`'s functions, if known.
+    pub fn get_machine_code_size(&self) -> Option<u64> {
+        self.functions.iter().filter_map(|function| function.machine_code_size).fold(None, |total, size| Some(total.unwrap_or(0) + size))
+    }
+}
+
 /// Represents a reference to an LLVM `Module`.
 /// The underlying module will be disposed when dropping this object.
 #[derive(Debug, PartialEq, Eq)]
@@ -251,6 +421,35 @@ impl Module {
         FunctionValue::new(function)
     }
 
+    /// Gets an iterator over the `FunctionValue`s defined in this `Module`, in order.
+    ///
+    /// The iterator walks the `Module`'s function list lazily, so functions added while
+    /// iterating forwards will also be visited.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let void_type = context.void_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    /// let fn_value1 = module.add_function("my_fn1", &fn_type, None);
+    /// let fn_value2 = module.add_function("my_fn2", &fn_type, None);
+    ///
+    /// assert_eq!(module.functions().collect::<Vec<_>>(), vec![fn_value1, fn_value2]);
+    /// assert_eq!(module.functions().rev().collect::<Vec<_>>(), vec![fn_value2, fn_value1]);
+    /// ```
+    pub fn functions(&self) -> FunctionIter {
+        FunctionIter {
+            module: self.module.get(),
+            front: None,
+            back: None,
+            finished: false,
+            _module: PhantomData,
+        }
+    }
+
     /// Gets a `FunctionValue` defined in this `Module` by its name.
     ///
     /// # Example
@@ -293,6 +492,86 @@ impl Module {
         Some(BasicTypeEnum::new(type_))
     }
 
+    /// Gets the name (also known as the identifier) of this `Module`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    ///
+    /// assert_eq!(module.get_name().to_str(), Ok("my_mod"));
+    ///
+    /// module.set_name("my_renamed_mod");
+    ///
+    /// assert_eq!(module.get_name().to_str(), Ok("my_renamed_mod"));
+    /// ```
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+    pub fn get_name(&self) -> &CStr {
+        use llvm_sys::core::LLVMGetModuleIdentifier;
+
+        let mut length = 0;
+
+        unsafe {
+            CStr::from_ptr(LLVMGetModuleIdentifier(self.module.get(), &mut length))
+        }
+    }
+
+    /// Sets the name (also known as the identifier) of this `Module`.
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+    pub fn set_name(&self, name: &str) {
+        use llvm_sys::core::LLVMSetModuleIdentifier;
+
+        let c_string = CString::new(name).expect("Conversion to CString failed unexpectedly");
+
+        unsafe {
+            LLVMSetModuleIdentifier(self.module.get(), c_string.as_ptr(), name.len())
+        }
+    }
+
+    // REVIEW: Source file names can only be read or set through LLVMGetSourceFileName and
+    // LLVMSetSourceFileName, which are only available from LLVM 7.0
+
+    /// Creates a copy of this `Module` in another `Context` by writing it to bitcode in memory
+    /// and parsing it back. This is useful to hand a template `Module` to a worker which has
+    /// its own `Context`. Use `clone` to copy a `Module` within its own `Context`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let void_type = context.void_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    ///
+    /// module.add_function("my_fn", &fn_type, None);
+    ///
+    /// let other_context = Context::create();
+    /// let other_module = module.clone_into(&other_context);
+    ///
+    /// assert_eq!(*other_module.get_context(), other_context);
+    /// assert!(other_module.get_function("my_fn").is_some());
+    /// ```
+    pub fn clone_into(&self, context: &Context) -> Self {
+        let buffer = self.write_bitcode_to_memory();
+        let module = Module::parse_bitcode_from_buffer(&buffer, context).expect("Module should always parse its own bitcode");
+
+        // The identifier isn't stored in bitcode, so the parsed module would be named after the buffer.
+        // Names needn't be valid unicode, so the raw bytes are copied over rather than using set_name
+        #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+        unsafe {
+            use llvm_sys::core::LLVMSetModuleIdentifier;
+
+            let name = self.get_name();
+
+            LLVMSetModuleIdentifier(module.module.get(), name.as_ptr(), name.to_bytes().len())
+        }
+
+        module
+    }
+
     // TODO: Make this take a targets::Target object by ref and call get_name
     pub fn set_target(&self, target_triple: &str) {
         let c_string = CString::new(target_triple).expect("Conversion to CString failed unexpectedly");
@@ -431,6 +710,89 @@ impl Module {
         GlobalValue::new(value)
     }
 
+    /// Adds a `GlobalValue` which aliases `aliasee` under another `name`. Both names will
+    /// refer to the same symbol. `type_` is the type of the aliased value, and `aliasee`
+    /// is a pointer to a global, a function or a constant expression thereof (ie a bitcast).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let void_type = context.void_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    /// let function = module.add_function("my_fn", &fn_type, None);
+    /// let alias = module.add_global_alias(&fn_type, &function.as_global_value().as_pointer_value(), "my_fn_alias");
+    ///
+    /// assert!(alias.is_alias());
+    /// assert_eq!(module.get_global_aliases(), vec![alias]);
+    /// ```
+    // REVIEW: IFuncs would be a natural addition here, but LLVMAddGlobalIFunc
+    // is only available from LLVM 9.0
+    pub fn add_global_alias(&self, type_: &AnyType, aliasee: &PointerValue, name: &str) -> GlobalValue {
+        let c_string = CString::new(name).expect("Conversion to CString failed unexpectedly");
+
+        let value = unsafe {
+            // LLVMAddAlias expects the pointer type of the alias rather than its value type
+            let address_space = LLVMGetPointerAddressSpace(LLVMTypeOf(aliasee.as_value_ref()));
+            let ptr_type = LLVMPointerType(type_.as_type_ref(), address_space);
+
+            LLVMAddAlias(self.module.get(), ptr_type, aliasee.as_value_ref(), c_string.as_ptr())
+        };
+
+        GlobalValue::new(value)
+    }
+
+    /// Gets all of the global aliases defined in this `Module`. The order of the aliases is unspecified.
+    ///
+    /// Only aliases which (transitively) alias a function or global of this `Module` are
+    /// found. An alias whose aliasee is not rooted in this `Module`'s function or global
+    /// lists, such as one aliasing a constant of another `Module` or a bare `inttoptr`
+    /// expression, will not be returned.
+    // LLVMGetFirstGlobalAlias and friends are only available from LLVM 7.0, so instead we find
+    // aliases by walking the users of every function and global (and of aliases themselves)
+    pub fn get_global_aliases(&self) -> Vec<GlobalValue> {
+        let mut aliases = Vec::new();
+        let mut visited = HashSet::new();
+        let mut worklist: Vec<LLVMValueRef> = self.functions().map(|function| function.as_value_ref())
+                                                              .chain(self.globals().map(|global| global.as_value_ref()))
+                                                              .collect();
+
+        while let Some(value) = worklist.pop() {
+            let mut use_ = unsafe {
+                LLVMGetFirstUse(value)
+            };
+
+            while !use_.is_null() {
+                let user = unsafe {
+                    LLVMGetUser(use_)
+                };
+
+                let is_alias = unsafe {
+                    !LLVMIsAGlobalAlias(user).is_null() && LLVMGetGlobalParent(user) == self.module.get()
+                };
+                let is_const_expr = unsafe {
+                    !LLVMIsAConstantExpr(user).is_null()
+                };
+
+                if (is_alias || is_const_expr) && visited.insert(user) {
+                    if is_alias {
+                        aliases.push(GlobalValue::new(user));
+                    }
+
+                    worklist.push(user);
+                }
+
+                use_ = unsafe {
+                    LLVMGetNextUse(use_)
+                };
+            }
+        }
+
+        aliases
+    }
+
     pub fn write_bitcode_to_path(&self, path: &Path) -> bool {
         let path_str = path.to_str().expect("Did not find a valid Unicode path string");
         let c_string = CString::new(path_str).expect("Conversion to CString failed unexpectedly");
@@ -467,6 +829,103 @@ impl Module {
         MemoryBuffer::new(memory_buffer)
     }
 
+    /// Parses a bitcode `MemoryBuffer` into a new `Module` belonging to the given `Context`.
+    ///
+    /// The `MemoryBuffer` is not consumed and may be reused afterwards.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::module::Module;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let buffer = module.write_bitcode_to_memory();
+    /// let module2 = Module::parse_bitcode_from_buffer(&buffer, &context).unwrap();
+    ///
+    /// assert_eq!(*module2.get_context(), context);
+    /// ```
+    pub fn parse_bitcode_from_buffer(buffer: &MemoryBuffer, context: &Context) -> Result<Self, LLVMString> {
+        let mut module = ptr::null_mut();
+        let mut err_string = ptr::null_mut();
+
+        let code = unsafe {
+            LLVMParseBitcodeInContext(*context.context, buffer.memory_buffer, &mut module, &mut err_string)
+        };
+
+        if code == 1 {
+            return Err(LLVMString::new(err_string));
+        }
+
+        Ok(Module::new(module, Some(context)))
+    }
+
+    /// Reads the bitcode file at `path` and parses it into a new `Module` belonging to the given `Context`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::module::Module;
+    ///
+    /// use std::path::Path;
+    ///
+    /// let path = Path::new("foo/bar.bc");
+    /// let context = Context::create();
+    /// let module = Module::parse_bitcode_from_path(&path, &context).unwrap();
+    ///
+    /// assert_eq!(*module.get_context(), context);
+    /// ```
+    pub fn parse_bitcode_from_path(path: &Path, context: &Context) -> Result<Self, LLVMString> {
+        let buffer = MemoryBuffer::create_from_file(path)?;
+
+        Module::parse_bitcode_from_buffer(&buffer, context)
+    }
+
+    /// Lazily parses a bitcode `MemoryBuffer` into a new `Module` belonging to the given `Context`.
+    ///
+    /// Only the module's globals and function declarations are read up front. Function bodies
+    /// stay in the buffer and are materialized by LLVM when they are first needed, such as when
+    /// linking, JIT compiling or running passes over the `Module`. Because of this, the
+    /// `Module` takes ownership of the `MemoryBuffer`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::module::Module;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let buffer = module.write_bitcode_to_memory();
+    /// let module2 = Module::parse_bitcode_from_buffer_lazily(buffer, &context).unwrap();
+    ///
+    /// assert_eq!(*module2.get_context(), context);
+    /// ```
+    pub fn parse_bitcode_from_buffer_lazily(buffer: MemoryBuffer, context: &Context) -> Result<Self, LLVMString> {
+        let mut module = ptr::null_mut();
+        let mut err_string = ptr::null_mut();
+
+        let code = unsafe {
+            LLVMGetBitcodeModuleInContext(*context.context, buffer.memory_buffer, &mut module, &mut err_string)
+        };
+
+        // LLVM takes ownership of the buffer, and will have already freed it on failure
+        forget(buffer);
+
+        if code == 1 {
+            return Err(LLVMString::new(err_string));
+        }
+
+        Ok(Module::new(module, Some(context)))
+    }
+
+    /// Lazily reads the bitcode file at `path` into a new `Module` belonging to the given `Context`.
+    /// See `parse_bitcode_from_buffer_lazily` for details on how function bodies are loaded.
+    pub fn parse_bitcode_from_path_lazily(path: &Path, context: &Context) -> Result<Self, LLVMString> {
+        let buffer = MemoryBuffer::create_from_file(path)?;
+
+        Module::parse_bitcode_from_buffer_lazily(buffer, context)
+    }
+
     /// Ensures that the current `Module` is valid, and returns a `Result`
     /// that describes whether or not it is, returning a LLVM allocated string on error.
     ///
@@ -600,6 +1059,92 @@ impl Module {
         slice.iter().map(|val| MetadataValue::new(*val)).collect()
     }
 
+    /// Adds a module flag with a basic value, such as an integer, under `key`.
+    ///
+    /// Module flags are stored in the `llvm.module.flags` named metadata. Each key may only be
+    /// added once, otherwise the `Module` will fail to verify.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::module::FlagBehavior;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let i32_type = context.i32_type();
+    ///
+    /// module.add_flag(FlagBehavior::Warning, "Debug Info Version", &i32_type.const_int(3, false));
+    ///
+    /// let flag = module.get_flag("Debug Info Version").unwrap();
+    ///
+    /// assert_eq!(flag.into_int_value(), i32_type.const_int(3, false));
+    /// assert_eq!(module.get_flag_behavior("Debug Info Version"), Some(FlagBehavior::Warning));
+    /// ```
+    pub fn add_flag(&self, behavior: FlagBehavior, key: &str, value: &BasicValue) {
+        self.add_flag_value_ref(behavior, key, value.as_value_ref())
+    }
+
+    /// Adds a module flag with a metadata value under `key`. This is required for the
+    /// `Require`, `Append` and `AppendUnique` behaviors.
+    pub fn add_metadata_flag(&self, behavior: FlagBehavior, key: &str, value: &MetadataValue) {
+        self.add_flag_value_ref(behavior, key, value.as_value_ref())
+    }
+
+    // Module flags are nodes of the form !{i32 <behavior>, !"<key>", <value>}
+    fn add_flag_value_ref(&self, behavior: FlagBehavior, key: &str, value: LLVMValueRef) {
+        let context = self.get_context();
+        let behavior = context.i32_type().const_int(behavior as u64, false);
+        let key = context.metadata_string(key);
+        let mut operands = [behavior.as_value_ref(), key.as_value_ref(), value];
+
+        let node = unsafe {
+            LLVMMDNodeInContext(*context.context, operands.as_mut_ptr(), operands.len() as u32)
+        };
+
+        self.add_global_metadata("llvm.module.flags", &MetadataValue::new(node));
+    }
+
+    fn get_flag_operands(&self, key: &str) -> Option<Vec<BasicMetadataValueEnum>> {
+        self.get_global_metadata("llvm.module.flags")
+            .iter()
+            .map(|flag| flag.get_node_values())
+            .find(|operands| {
+                operands.len() == 3 && match operands[1] {
+                    BasicMetadataValueEnum::MetadataValue(ref flag_key) => flag_key.get_string_value().and_then(|flag_key| flag_key.to_str().ok()) == Some(key),
+                    _ => false,
+                }
+            })
+    }
+
+    /// Gets the value of the module flag under `key`, if any.
+    pub fn get_flag(&self, key: &str) -> Option<BasicMetadataValueEnum> {
+        self.get_flag_operands(key).map(|operands| operands[2])
+    }
+
+    /// Gets the `FlagBehavior` of the module flag under `key`, if any. Returns `None` if the
+    /// flag's behavior operand isn't a known behavior, as may be the case for malformed IR.
+    pub fn get_flag_behavior(&self, key: &str) -> Option<FlagBehavior> {
+        use llvm_sys::core::{LLVMGetIntTypeWidth, LLVMIsAConstantInt};
+
+        let operands = self.get_flag_operands(key)?;
+        let behavior = operands[0].as_value_ref();
+
+        // LLVMConstIntGetZExtValue asserts on non constant ints and ints wider than 64 bits
+        let is_small_const_int = unsafe {
+            !LLVMIsAConstantInt(behavior).is_null() && LLVMGetIntTypeWidth(LLVMTypeOf(behavior)) <= 64
+        };
+
+        if !is_small_const_int {
+            return None;
+        }
+
+        let behavior = unsafe {
+            LLVMConstIntGetZExtValue(behavior)
+        };
+
+        FlagBehavior::new(behavior)
+    }
+
     pub fn get_first_global(&self) -> Option<GlobalValue> {
         let value = unsafe {
             LLVMGetFirstGlobal(self.module.get())
@@ -624,6 +1169,284 @@ impl Module {
         Some(GlobalValue::new(value))
     }
 
+    /// Registers `function` to be run on startup, before `main`, by appending it to `llvm.global_ctors`.
+    ///
+    /// Constructors run in ascending order of `priority`, where 65535 is the usual default. If
+    /// `associated_data` is given, the constructor is only kept if that global is kept as well.
+    /// `function` is called as a `void ()`, and is bitcast to that type if its signature differs.
+    /// Calling this repeatedly extends the existing list, and lists from different `Module`s are
+    /// merged when they are linked together.
+    ///
+    /// An existing list using the older two field `{ i32, void ()* }` layout is upgraded to the
+    /// three field layout. An error is returned if the existing list has any other layout.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let void_type = context.void_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    /// let function = module.add_function("my_init", &fn_type, None);
+    ///
+    /// assert!(module.add_global_constructor(&function, 65535, None).is_ok());
+    /// assert!(module.get_global("llvm.global_ctors").is_some());
+    /// ```
+    pub fn add_global_constructor(&self, function: &FunctionValue, priority: u32, associated_data: Option<&GlobalValue>) -> Result<(), LLVMString> {
+        self.add_global_structor("llvm.global_ctors", function, priority, associated_data)
+    }
+
+    /// Registers `function` to be run on shutdown by appending it to `llvm.global_dtors`.
+    /// See `add_global_constructor` for details on the parameters.
+    pub fn add_global_destructor(&self, function: &FunctionValue, priority: u32, associated_data: Option<&GlobalValue>) -> Result<(), LLVMString> {
+        self.add_global_structor("llvm.global_dtors", function, priority, associated_data)
+    }
+
+    // Each entry has the layout { i32 priority, void ()* function, i8* associated_data }
+    fn add_global_structor(&self, name: &str, function: &FunctionValue, priority: u32, associated_data: Option<&GlobalValue>) -> Result<(), LLVMString> {
+        use llvm_sys::core::{LLVMFunctionType, LLVMGetTypeKind, LLVMIsAConstantStruct, LLVMVoidTypeInContext};
+        use llvm_sys::LLVMTypeKind;
+
+        let context = self.get_context();
+        let i32_type = context.i32_type();
+        let i8_ptr_type = context.i8_type().ptr_type(AddressSpace::Generic);
+        let null_data = i8_ptr_type.const_null().as_value_ref();
+        let fn_ptr_type = unsafe {
+            let fn_type = LLVMFunctionType(LLVMVoidTypeInContext(*context.context), ptr::null_mut(), 0, false as i32);
+
+            LLVMPointerType(fn_type, 0)
+        };
+        let create_entry = |priority, function, data| {
+            let mut fields = [priority, unsafe { LLVMConstPointerCast(function, fn_ptr_type) }, data];
+
+            unsafe {
+                LLVMConstStructInContext(*context.context, fields.as_mut_ptr(), fields.len() as u32, false as i32)
+            }
+        };
+
+        let priority = i32_type.const_int(priority as u64, false);
+        let data = match associated_data {
+            Some(global) => unsafe {
+                LLVMConstPointerCast(global.as_value_ref(), i8_ptr_type.as_type_ref())
+            },
+            None => null_data,
+        };
+        let entry = create_entry(priority.as_value_ref(), function.as_value_ref(), data);
+        let entry_type = unsafe {
+            LLVMTypeOf(entry)
+        };
+
+        let mut entries = Vec::new();
+
+        for existing_entry in self.get_global_array_elements(name) {
+            let existing_entry_type = unsafe {
+                LLVMTypeOf(existing_entry)
+            };
+
+            if existing_entry_type == entry_type {
+                entries.push(existing_entry);
+
+                continue;
+            }
+
+            // Lists written before associated data was introduced have no third field
+            let is_two_field_entry = unsafe {
+                !LLVMIsAConstantStruct(existing_entry).is_null()
+                    && LLVMGetNumOperands(existing_entry) == 2
+                    && LLVMTypeOf(LLVMGetOperand(existing_entry, 0)) == i32_type.as_type_ref()
+                    && LLVMGetTypeKind(LLVMTypeOf(LLVMGetOperand(existing_entry, 1))) == LLVMTypeKind::LLVMPointerTypeKind
+            };
+
+            if !is_two_field_entry {
+                return Err(LLVMString::create(&format!("{} has an unsupported entry layout", name)));
+            }
+
+            let upgraded_entry = unsafe {
+                create_entry(LLVMGetOperand(existing_entry, 0), LLVMGetOperand(existing_entry, 1), null_data)
+            };
+
+            entries.push(upgraded_entry);
+        }
+
+        entries.push(entry);
+
+        self.replace_global_array(name, entry_type, entries);
+
+        Ok(())
+    }
+
+    /// Marks a global as used by appending it to `llvm.used`. Neither the
+    /// compiler nor the linker will remove it, even if nothing in IR appears to reference it.
+    /// Functions are marked with `mark_function_used`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let i32_type = context.i32_type();
+    /// let global = module.add_global(&i32_type, None, "my_global");
+    ///
+    /// module.mark_used(&global);
+    ///
+    /// assert_eq!(module.get_used(), vec![global]);
+    /// ```
+    pub fn mark_used(&self, value: &GlobalValue) {
+        self.append_to_used_array("llvm.used", value)
+    }
+
+    /// Marks a function as used by appending it to `llvm.used`. See `mark_used`.
+    pub fn mark_function_used(&self, function: &FunctionValue) {
+        self.append_to_used_array("llvm.used", &function.as_global_value())
+    }
+
+    /// Marks a global as used by appending it to `llvm.compiler.used`. The compiler
+    /// will not remove it, but unlike `mark_used` the linker may still do so. Functions are
+    /// marked with `mark_function_compiler_used`.
+    pub fn mark_compiler_used(&self, value: &GlobalValue) {
+        self.append_to_used_array("llvm.compiler.used", value)
+    }
+
+    /// Marks a function as used by appending it to `llvm.compiler.used`. See `mark_compiler_used`.
+    pub fn mark_function_compiler_used(&self, function: &FunctionValue) {
+        self.append_to_used_array("llvm.compiler.used", &function.as_global_value())
+    }
+
+    /// Gets the globals and functions which have been marked as used. See `mark_used`.
+    pub fn get_used(&self) -> Vec<GlobalValue> {
+        self.get_used_array("llvm.used")
+    }
+
+    /// Gets the globals and functions which have been marked as used by the compiler. See `mark_compiler_used`.
+    pub fn get_compiler_used(&self) -> Vec<GlobalValue> {
+        self.get_used_array("llvm.compiler.used")
+    }
+
+    // Entries are i8* casts of the used globals, which we strip back off
+    fn get_used_array(&self, name: &str) -> Vec<GlobalValue> {
+        self.get_global_array_elements(name)
+            .into_iter()
+            .map(|mut value| {
+                while unsafe { !LLVMIsAConstantExpr(value).is_null() } {
+                    value = unsafe {
+                        LLVMGetOperand(value, 0)
+                    };
+                }
+
+                GlobalValue::new(value)
+            })
+            .collect()
+    }
+
+    fn append_to_used_array(&self, name: &str, value: &GlobalValue) {
+        if self.get_used_array(name).iter().any(|used| used.as_value_ref() == value.as_value_ref()) {
+            return;
+        }
+
+        let i8_ptr_type = self.get_context().i8_type().ptr_type(AddressSpace::Generic).as_type_ref();
+
+        // Existing entries may be pointers of another type (or address space), so they're
+        // all cast to i8* to keep the array homogeneous
+        let entries: Vec<LLVMValueRef> = self.get_global_array_elements(name)
+            .into_iter()
+            .chain(Some(value.as_value_ref()))
+            .map(|entry| unsafe { LLVMConstPointerCast(entry, i8_ptr_type) })
+            .collect();
+
+        let global = self.replace_global_array(name, i8_ptr_type, entries);
+
+        global.set_section("llvm.metadata");
+    }
+
+    // Reads the elements of an array global's initializer, such as llvm.global_ctors or llvm.used
+    fn get_global_array_elements(&self, name: &str) -> Vec<LLVMValueRef> {
+        let initializer = match self.get_global(name) {
+            Some(global) => unsafe {
+                LLVMGetInitializer(global.as_value_ref())
+            },
+            None => return Vec::new(),
+        };
+
+        // An empty array may be a zeroinitializer rather than a ConstantArray
+        if initializer.is_null() || unsafe { LLVMIsAConstantArray(initializer).is_null() } {
+            return Vec::new();
+        }
+
+        let count = unsafe {
+            LLVMGetNumOperands(initializer)
+        };
+
+        (0..count as u32).map(|i| unsafe { LLVMGetOperand(initializer, i) }).collect()
+    }
+
+    // Appending globals can't be resized in place, so extending one means replacing it
+    // with a new global holding `values`, which must all be of `element_type`
+    fn replace_global_array(&self, name: &str, element_type: LLVMTypeRef, mut values: Vec<LLVMValueRef>) -> GlobalValue {
+        let old_global = self.get_global(name);
+
+        // Frees up the name for the new global
+        if let Some(ref old_global) = old_global {
+            old_global.set_name("");
+        }
+
+        let c_string = CString::new(name).expect("Conversion to CString failed unexpectedly");
+
+        let global = unsafe {
+            let array = LLVMConstArray(element_type, values.as_mut_ptr(), values.len() as u32);
+            let global = LLVMAddGlobal(self.module.get(), LLVMTypeOf(array), c_string.as_ptr());
+
+            LLVMSetLinkage(global, LLVMLinkage::LLVMAppendingLinkage);
+            LLVMSetInitializer(global, array);
+
+            global
+        };
+
+        if let Some(old_global) = old_global {
+            unsafe {
+                LLVMReplaceAllUsesWith(old_global.as_value_ref(), LLVMConstBitCast(global, LLVMTypeOf(old_global.as_value_ref())));
+
+                old_global.delete().expect("Global should have no uses after being replaced");
+            }
+        }
+
+        GlobalValue::new(global)
+    }
+
+    /// Gets an iterator over the `GlobalValue`s defined in this `Module`, in order.
+    ///
+    /// The iterator walks the `Module`'s global list lazily, so globals added while
+    /// iterating forwards will also be visited.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let i8_type = context.i8_type();
+    /// let global1 = module.add_global(&i8_type, None, "my_global1");
+    /// let global2 = module.add_global(&i8_type, None, "my_global2");
+    ///
+    /// assert_eq!(module.globals().collect::<Vec<_>>(), vec![global1, global2]);
+    /// assert_eq!(module.globals().rev().collect::<Vec<_>>(), vec![global2, global1]);
+    /// ```
+    pub fn globals(&self) -> GlobalIter {
+        GlobalIter {
+            module: self.module.get(),
+            front: None,
+            back: None,
+            finished: false,
+            _module: PhantomData,
+        }
+    }
+
+    // REVIEW: Iterating over named metadata needs LLVMGetFirstNamedMetadata and friends,
+    // which are only available from LLVM 7.0
+
+    // REVIEW: COMDAT groups would be a natural addition here, but LLVMGetOrInsertComdat
+    // and friends are only available from LLVM 7.0
     pub fn get_global(&self, name: &str) -> Option<GlobalValue> {
         let c_string = CString::new(name).expect("Conversion to CString failed unexpectedly");
         let value = unsafe {
@@ -636,6 +1459,163 @@ impl Module {
 
         Some(GlobalValue::new(value))
     }
+
+    /// Links another `Module` into this one, consuming it. Symbols from `other` will be
+    /// resolved against the symbols already defined in this `Module`.
+    ///
+    /// Both `Module`s must belong to the same `Context`, and `other` may not be owned by
+    /// an `ExecutionEngine`. Any error reported by the linker, such as a duplicate symbol
+    /// definition, is returned as a LLVM allocated string.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("mod");
+    /// let other_module = context.create_module("mod2");
+    /// let void_type = context.void_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    ///
+    /// other_module.add_function("my_fn", &fn_type, None);
+    ///
+    /// assert!(module.link_in_module(other_module).is_ok());
+    /// assert!(module.get_function("my_fn").is_some());
+    /// ```
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+    pub fn link_in_module(&self, mut other: Self) -> Result<(), LLVMString> {
+        use libc::{c_char, c_void};
+        use llvm_sys::core::{LLVMContextGetDiagnosticContext, LLVMContextGetDiagnosticHandler, LLVMContextSetDiagnosticHandler};
+        use llvm_sys::linker::LLVMLinkModules2;
+        use support::error_handling::get_error_str_diagnostic_handler;
+
+        if other.owned_by_ee.borrow().is_some() {
+            return Err(LLVMString::create("Cannot link a module which is already owned by an ExecutionEngine"));
+        }
+
+        let context = self.get_context();
+
+        if *context != *other.get_context() {
+            return Err(LLVMString::create("Cannot link modules which belong to different contexts"));
+        }
+
+        let mut err_str: *mut c_char = ptr::null_mut();
+
+        // LLVMLinkModules2 only reports errors through the context's diagnostic handler,
+        // so we temporarily swap in our own to capture them
+        let code = unsafe {
+            let previous_handler = LLVMContextGetDiagnosticHandler(*context.context);
+            let previous_context = LLVMContextGetDiagnosticContext(*context.context);
+
+            LLVMContextSetDiagnosticHandler(*context.context, Some(get_error_str_diagnostic_handler), &mut err_str as *mut *mut c_char as *mut c_void);
+
+            let code = LLVMLinkModules2(self.module.get(), other.module.get());
+
+            LLVMContextSetDiagnosticHandler(*context.context, previous_handler, previous_context);
+
+            code
+        };
+
+        // The source module is always destroyed by LLVM, so we must not dispose of it again.
+        // Its reference to the context still needs to be released though.
+        drop(other.non_global_context.take());
+        forget(other);
+
+        if code == 1 {
+            if err_str.is_null() {
+                return Err(LLVMString::create("Failed to link modules"));
+            }
+
+            return Err(LLVMString::new(err_str));
+        }
+
+        Ok(())
+    }
+
+    /// Counts the functions, globals, basic blocks and instructions in this `Module`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::values::InstructionOpcode;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let void_type = context.void_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    /// let function = module.add_function("my_fn", &fn_type, None);
+    /// let entry = context.append_basic_block(&function, "entry");
+    ///
+    /// builder.position_at_end(&entry);
+    /// builder.build_return(None);
+    ///
+    /// let statistics = module.statistics();
+    /// let function_statistics = statistics.get_function("my_fn").unwrap();
+    ///
+    /// assert_eq!(statistics.count_functions(), 1);
+    /// assert_eq!(function_statistics.count_basic_blocks(), 1);
+    /// assert_eq!(function_statistics.count_opcode(InstructionOpcode::Return), 1);
+    /// ```
+    pub fn statistics(&self) -> ModuleStatistics {
+        ModuleStatistics {
+            functions: self.functions().map(|function| FunctionStatistics::new(&function)).collect(),
+            globals: self.globals().count() as u32,
+        }
+    }
+
+    /// Like `statistics`, but also compiles this `Module` to an object file with `target_machine`
+    /// to find the size of the machine code emitted for each function.
+    ///
+    /// Sizes are read from the object file's symbol table, so they are only as accurate as
+    /// the object format allows. For example, Mach-O does not record symbol sizes.
+    pub fn statistics_with_machine_code(&self, target_machine: &TargetMachine) -> Result<ModuleStatistics, LLVMString> {
+        let mut statistics = self.statistics();
+        let memory_buffer = target_machine.write_to_memory_buffer(self, FileType::Object)?;
+        let object_file = memory_buffer.create_object_file();
+        let mut sizes = HashMap::new();
+
+        for symbol in object_file.get_symbols() {
+            let name = symbol.get_name().to_string_lossy().into_owned();
+
+            sizes.insert(name, symbol.size());
+        }
+
+        for function in statistics.functions.iter_mut().filter(|function| function.basic_blocks > 0) {
+            // Some targets, such as Darwin, prefix symbol names with an underscore
+            function.machine_code_size = sizes.get(&function.name)
+                .or_else(|| sizes.get(&format!("_{}", function.name)))
+                .cloned();
+        }
+
+        Ok(statistics)
+    }
+
+    /// Structurally compares this `Module` against `other`, returning a readable description
+    /// of each difference found. An empty `Vec` means both `Module`s are equivalent.
+    ///
+    /// Named struct types, globals and functions are matched up by name, so the order in which
+    /// they were declared does not matter. Function bodies are compared block by block and
+    /// instruction by instruction, ignoring the names and numbering of local values such as
+    /// parameters, basic blocks and instruction results.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("mod");
+    /// let other_module = context.create_module("mod2");
+    /// let i32_type = context.i32_type();
+    ///
+    /// module.add_global(&i32_type, None, "my_global");
+    ///
+    /// assert!(module.diff(&module).is_empty());
+    /// assert_eq!(other_module.diff(&module), vec!["global @my_global only exists in the right module"]);
+    /// ```
+    pub fn diff(&self, other: &Module) -> Vec<String> {
+        diff_modules(self, other)
+    }
 }
 
 impl Clone for Module {
@@ -661,3 +1641,81 @@ impl Drop for Module {
         // Context & EE will drop naturally if they are unique references at this point
     }
 }
+
+// Each side of the iterator remembers the last value it yielded and only looks up its
+// neighbour when asked for the next item, rather than collecting the list up front.
+// Iteration stops once either side reaches a value already yielded by the other.
+macro_rules! module_list_iter {
+    ($(#[$attr:meta])* $iter_name:ident: $value:ident, $wrap:expr, $get_first:ident, $get_last:ident, $get_next:ident, $get_previous:ident) => (
+        $(#[$attr])*
+        #[derive(Debug)]
+        pub struct $iter_name<'a> {
+            module: LLVMModuleRef,
+            front: Option<LLVMValueRef>,
+            back: Option<LLVMValueRef>,
+            finished: bool,
+            _module: PhantomData<&'a Module>,
+        }
+
+        impl<'a> Iterator for $iter_name<'a> {
+            type Item = $value;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.finished {
+                    return None;
+                }
+
+                let value = unsafe {
+                    match self.front {
+                        Some(value) => $get_next(value),
+                        None => $get_first(self.module),
+                    }
+                };
+
+                if value.is_null() || Some(value) == self.back {
+                    self.finished = true;
+
+                    return None;
+                }
+
+                self.front = Some(value);
+
+                Some($wrap(value))
+            }
+        }
+
+        impl<'a> DoubleEndedIterator for $iter_name<'a> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.finished {
+                    return None;
+                }
+
+                let value = unsafe {
+                    match self.back {
+                        Some(value) => $get_previous(value),
+                        None => $get_last(self.module),
+                    }
+                };
+
+                if value.is_null() || Some(value) == self.front {
+                    self.finished = true;
+
+                    return None;
+                }
+
+                self.back = Some(value);
+
+                Some($wrap(value))
+            }
+        }
+    );
+}
+
+module_list_iter! {
+    /// An iterator over the `FunctionValue`s of a `Module`. See `Module::functions`.
+    FunctionIter: FunctionValue, |value| FunctionValue::new(value).expect("Function list should only contain functions"), LLVMGetFirstFunction, LLVMGetLastFunction, LLVMGetNextFunction, LLVMGetPreviousFunction
+}
+module_list_iter! {
+    /// An iterator over the `GlobalValue`s of a `Module`. See `Module::globals`.
+    GlobalIter: GlobalValue, GlobalValue::new, LLVMGetFirstGlobal, LLVMGetLastGlobal, LLVMGetNextGlobal, LLVMGetPreviousGlobal
+}
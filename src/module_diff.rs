@@ -0,0 +1,236 @@
+//! The structural comparison behind `Module::diff`.
+
+use llvm_sys::core::{LLVMBasicBlockAsValue, LLVMGetElementType, LLVMGetFirstBasicBlock, LLVMGetFirstInstruction, LLVMGetFirstParam, LLVMGetLinkage, LLVMGetNextBasicBlock, LLVMGetNextInstruction, LLVMGetNextParam, LLVMPrintTypeToString, LLVMSetValueName, LLVMTypeOf};
+use llvm_sys::prelude::LLVMValueRef;
+
+use std::cmp::max;
+use std::collections::BTreeMap;
+
+use module::{Linkage, Module};
+use support::LLVMString;
+use values::AsValueRef;
+
+pub(crate) fn diff_modules(left: &Module, right: &Module) -> Vec<String> {
+    let mut differences = Vec::new();
+    let left = ModuleDefinitions::new(left);
+    let right = ModuleDefinitions::new(right);
+
+    diff_definitions(&mut differences, "type", &left.types, &right.types);
+    diff_definitions(&mut differences, "global", &left.globals, &right.globals);
+
+    for (name, left_function) in &left.functions {
+        match right.functions.get(name) {
+            Some(right_function) => diff_functions(&mut differences, name, left_function, right_function),
+            None => differences.push(format!("function {} only exists in the left module", name)),
+        }
+    }
+
+    for name in right.functions.keys().filter(|name| !left.functions.contains_key(*name)) {
+        differences.push(format!("function {} only exists in the right module", name));
+    }
+
+    differences
+}
+
+// Everything being compared is read out of a single printed copy of the module, as printing
+// values one at a time makes LLVM renumber the whole function (or module) on every call.
+// LLVM 6 has no way to enumerate a module's types, so those can only come from the text anyway.
+struct ModuleDefinitions {
+    types: BTreeMap<String, String>,
+    globals: BTreeMap<String, String>,
+    functions: BTreeMap<String, FunctionDefinition>,
+}
+
+impl ModuleDefinitions {
+    // Local values are compared by position rather than by name. Rather than rewriting names in
+    // the printed text, where a local can't reliably be told apart from a named type or a quoted
+    // name, a copy of the module has all of its local names cleared so that LLVM numbers them in
+    // order when printing
+    fn new(module: &Module) -> Self {
+        let module = module.clone();
+
+        for function in module.functions() {
+            clear_local_names(function.as_value_ref());
+        }
+
+        let printed = module.print_to_string().to_string();
+        let lines: Vec<&str> = printed.lines().collect();
+
+        let types = lines.iter()
+            .filter(|line| line.starts_with('%'))
+            .filter_map(|line| line.find(" = type ").map(|index| (line[..index].to_string(), line.to_string())))
+            .collect();
+
+        // Globals are printed one per line, in order and ahead of any aliases
+        let globals = module.globals()
+            .zip(lines.iter().filter(|line| line.starts_with('@')))
+            .map(|(global, line)| (format!("@{}", global.get_name().to_string_lossy()), line.to_string()))
+            .collect();
+
+        // Functions are printed in order, each starting with its `declare` or `define` line
+        let function_starts = lines.iter()
+            .enumerate()
+            .filter(|&(_, line)| line.starts_with("declare ") || line.starts_with("define "))
+            .map(|(index, _)| index);
+
+        let functions = module.functions()
+            .zip(function_starts)
+            .map(|(function, start)| (format!("@{}", function.get_name().to_string_lossy()), FunctionDefinition::new(function.as_value_ref(), &lines[start..])))
+            .collect();
+
+        ModuleDefinitions {
+            types,
+            globals,
+            functions,
+        }
+    }
+}
+
+fn clear_local_names(function: LLVMValueRef) {
+    let empty_name = b"\0".as_ptr() as *const _;
+
+    unsafe {
+        let mut param = LLVMGetFirstParam(function);
+
+        while !param.is_null() {
+            LLVMSetValueName(param, empty_name);
+
+            param = LLVMGetNextParam(param);
+        }
+
+        let mut basic_block = LLVMGetFirstBasicBlock(function);
+
+        while !basic_block.is_null() {
+            LLVMSetValueName(LLVMBasicBlockAsValue(basic_block), empty_name);
+
+            let mut instruction = LLVMGetFirstInstruction(basic_block);
+
+            while !instruction.is_null() {
+                LLVMSetValueName(instruction, empty_name);
+
+                instruction = LLVMGetNextInstruction(instruction);
+            }
+
+            basic_block = LLVMGetNextBasicBlock(basic_block);
+        }
+    }
+}
+
+// A function's signature and per block instructions, with local values numbered
+// by their position in the function so that differently named locals compare equal
+struct FunctionDefinition {
+    signature: String,
+    basic_blocks: Vec<Vec<String>>,
+}
+
+impl FunctionDefinition {
+    // `lines` starts at the function's `declare` or `define` line in the printed module.
+    // Within a body, instructions are indented by two spaces, anything indented further
+    // (such as switch cases or landingpad clauses) continues the previous instruction, and
+    // every block but the unnamed entry block is introduced by an unindented label line
+    fn new(function: LLVMValueRef, lines: &[&str]) -> Self {
+        let mut basic_blocks: Vec<Vec<String>> = Vec::new();
+
+        if lines[0].ends_with('{') {
+            basic_blocks.push(Vec::new());
+
+            for line in lines[1..].iter().take_while(|line| **line != "}") {
+                if line.trim().is_empty() {
+                    continue;
+                }
+
+                if !line.starts_with(' ') {
+                    basic_blocks.push(Vec::new());
+                    continue;
+                }
+
+                let instructions = basic_blocks.last_mut().expect("The entry block is always present");
+                let is_continuation = line.starts_with("   ") || line.trim_left().starts_with(']');
+
+                match instructions.last_mut() {
+                    Some(instruction) if is_continuation => {
+                        instruction.push(' ');
+                        instruction.push_str(line.trim());
+                    },
+                    _ => instructions.push(line.trim().to_string()),
+                }
+            }
+        }
+
+        let (function_type, linkage) = unsafe {
+            let function_type = LLVMString::new(LLVMPrintTypeToString(LLVMGetElementType(LLVMTypeOf(function))));
+
+            (function_type, Linkage::new(LLVMGetLinkage(function)))
+        };
+
+        FunctionDefinition {
+            signature: format!("{} ({:?})", function_type.to_string(), linkage),
+            basic_blocks,
+        }
+    }
+}
+
+fn diff_definitions(differences: &mut Vec<String>, kind: &str, left: &BTreeMap<String, String>, right: &BTreeMap<String, String>) {
+    for (name, left_definition) in left {
+        match right.get(name) {
+            Some(right_definition) if left_definition != right_definition => {
+                differences.push(format!("{} {} differs: `{}` != `{}`", kind, name, left_definition, right_definition));
+            },
+            Some(_) => {},
+            None => differences.push(format!("{} {} only exists in the left module", kind, name)),
+        }
+    }
+
+    for name in right.keys().filter(|name| !left.contains_key(*name)) {
+        differences.push(format!("{} {} only exists in the right module", kind, name));
+    }
+}
+
+fn diff_functions(differences: &mut Vec<String>, name: &str, left: &FunctionDefinition, right: &FunctionDefinition) {
+    if left.signature != right.signature {
+        differences.push(format!("function {} signature differs: `{}` != `{}`", name, left.signature, right.signature));
+    }
+
+    for (index, (left_instructions, right_instructions)) in left.basic_blocks.iter().zip(&right.basic_blocks).enumerate() {
+        diff_instructions(differences, &format!("function {} block {}", name, index), left_instructions, right_instructions);
+    }
+
+    for index in right.basic_blocks.len()..left.basic_blocks.len() {
+        differences.push(format!("function {} block {} only exists in the left module", name, index));
+    }
+
+    for index in left.basic_blocks.len()..right.basic_blocks.len() {
+        differences.push(format!("function {} block {} only exists in the right module", name, index));
+    }
+}
+
+// Reports removed (-) and added (+) instructions based on the longest common subsequence
+// of both blocks, so that a single inserted instruction doesn't mark the rest of the block as changed
+fn diff_instructions(differences: &mut Vec<String>, prefix: &str, left: &[String], right: &[String]) {
+    let mut lengths = vec![vec![0; right.len() + 1]; left.len() + 1];
+
+    for i in (0..left.len()).rev() {
+        for j in (0..right.len()).rev() {
+            lengths[i][j] = if left[i] == right[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                max(lengths[i + 1][j], lengths[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+
+    while i < left.len() || j < right.len() {
+        if i < left.len() && j < right.len() && left[i] == right[j] {
+            i += 1;
+            j += 1;
+        } else if j == right.len() || (i < left.len() && lengths[i + 1][j] >= lengths[i][j + 1]) {
+            differences.push(format!("{}: - `{}`", prefix, left[i]));
+            i += 1;
+        } else {
+            differences.push(format!("{}: + `{}`", prefix, right[j]));
+            j += 1;
+        }
+    }
+}
@@ -44,10 +44,27 @@ impl Drop for ObjectFile {
     }
 }
 
+/// An iterator over the `Section`s of an `ObjectFile`.
+///
+/// LLVM only provides a single cursor per iterator, and each yielded `Section` reads through
+/// that cursor rather than holding a copy of its data. A `Section` is therefore only valid
+/// until the next call to `next`: it must be used inside the loop body, and collecting the
+/// `Section`s first (ie with `collect`) leaves them all pointing past the last section.
+///
+/// # Example
+/// ```no_run
+/// # use inkwell::object_file::ObjectFile;
+/// # fn print_sections(object_file: &ObjectFile) {
+/// for section in object_file.get_sections() {
+///     println!("{:?}: {} bytes", section.get_name(), section.size());
+/// }
+/// # }
+/// ```
 #[derive(Debug)]
 pub struct SectionIterator {
     section_iterator: LLVMSectionIteratorRef,
     object_file: LLVMObjectFileRef,
+    started: bool,
 }
 
 impl SectionIterator {
@@ -56,7 +73,8 @@ impl SectionIterator {
 
         SectionIterator {
             section_iterator,
-            object_file
+            object_file,
+            started: false,
         }
     }
 }
@@ -65,6 +83,16 @@ impl Iterator for SectionIterator {
     type Item = Section;
 
     fn next(&mut self) -> Option<Self::Item> {
+        // The returned item reads from the underlying iterator, so we only move past it
+        // once the next item is requested
+        if self.started {
+            unsafe {
+                LLVMMoveToNextSection(self.section_iterator)
+            }
+        }
+
+        self.started = true;
+
         // REVIEW: Should it compare against 1? End checking order might also be off
         let at_end = unsafe {
             LLVMIsSectionIteratorAtEnd(self.object_file, self.section_iterator) == 1
@@ -76,10 +104,6 @@ impl Iterator for SectionIterator {
 
         let section = Section::new(self.section_iterator, self.object_file);
 
-        unsafe {
-            LLVMMoveToNextSection(self.section_iterator)
-        }
-
         Some(section)
     }
 }
@@ -92,6 +116,7 @@ impl Drop for SectionIterator {
     }
 }
 
+/// A section of an `ObjectFile`, valid until its `SectionIterator` moves on. See `SectionIterator`.
 #[derive(Debug)]
 pub struct Section {
     section: LLVMSectionIteratorRef,
@@ -141,11 +166,14 @@ impl Section {
     }
 }
 
+/// An iterator over the `Relocation`s of a `Section`. As with `SectionIterator`, each yielded
+/// `Relocation` is only valid until the next call to `next`.
 #[derive(Debug)]
 pub struct RelocationIterator {
     relocation_iterator: LLVMRelocationIteratorRef,
     section_iterator: LLVMSectionIteratorRef,
     object_file: LLVMObjectFileRef,
+    started: bool,
 }
 
 impl RelocationIterator {
@@ -156,6 +184,7 @@ impl RelocationIterator {
             relocation_iterator,
             section_iterator,
             object_file,
+            started: false,
         }
     }
 }
@@ -164,6 +193,14 @@ impl Iterator for RelocationIterator {
     type Item = Relocation;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if self.started {
+            unsafe {
+                LLVMMoveToNextRelocation(self.relocation_iterator)
+            }
+        }
+
+        self.started = true;
+
         // REVIEW: Should it compare against 1? End checking order might also be off
         let at_end = unsafe {
             LLVMIsRelocationIteratorAtEnd(self.section_iterator, self.relocation_iterator) == 1
@@ -175,10 +212,6 @@ impl Iterator for RelocationIterator {
 
         let relocation = Relocation::new(self.relocation_iterator, self.object_file);
 
-        unsafe {
-            LLVMMoveToNextRelocation(self.relocation_iterator)
-        }
-
         Some(relocation)
     }
 }
@@ -191,6 +224,7 @@ impl Drop for RelocationIterator {
     }
 }
 
+/// A relocation of a `Section`, valid until its `RelocationIterator` moves on.
 #[derive(Debug)]
 pub struct Relocation {
     relocation: LLVMRelocationIteratorRef,
@@ -240,10 +274,13 @@ impl Relocation {
     }
 }
 
+/// An iterator over the `Symbol`s of an `ObjectFile`. As with `SectionIterator`, each yielded
+/// `Symbol` is only valid until the next call to `next`.
 #[derive(Debug)]
 pub struct SymbolIterator {
     symbol_iterator: LLVMSymbolIteratorRef,
     object_file: LLVMObjectFileRef,
+    started: bool,
 }
 
 impl SymbolIterator {
@@ -253,6 +290,7 @@ impl SymbolIterator {
         SymbolIterator {
             symbol_iterator,
             object_file,
+            started: false,
         }
     }
 }
@@ -261,6 +299,14 @@ impl Iterator for SymbolIterator {
     type Item = Symbol;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if self.started {
+            unsafe {
+                LLVMMoveToNextSymbol(self.symbol_iterator)
+            }
+        }
+
+        self.started = true;
+
         // REVIEW: Should it compare against 1? End checking order might also be off
         let at_end = unsafe {
             LLVMIsSymbolIteratorAtEnd(self.object_file, self.symbol_iterator) == 1
@@ -272,10 +318,6 @@ impl Iterator for SymbolIterator {
 
         let symbol = Symbol::new(self.symbol_iterator);
 
-        unsafe {
-            LLVMMoveToNextSymbol(self.symbol_iterator)
-        }
-
         Some(symbol)
     }
 }
@@ -288,6 +330,7 @@ impl Drop for SymbolIterator {
     }
 }
 
+/// A symbol of an `ObjectFile`, valid until its `SymbolIterator` moves on.
 #[derive(Debug)]
 pub struct Symbol {
     symbol: LLVMSymbolIteratorRef,
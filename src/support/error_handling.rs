@@ -1,3 +1,12 @@
+#[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+use libc::{c_char, c_void};
+#[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+use llvm_sys::LLVMDiagnosticSeverity;
+#[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+use llvm_sys::core::{LLVMDisposeMessage, LLVMGetDiagInfoDescription, LLVMGetDiagInfoSeverity};
+#[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+use llvm_sys::prelude::LLVMDiagnosticInfoRef;
+
 // Installs an error handler to be called before LLVM exits
 // REVIEW: Maybe it's possible to have a safe wrapper? If we can
 // wrap the provided function input ptr into a &CStr somehow
@@ -32,3 +41,56 @@ pub fn reset_fatal_error_handler() {
         LLVMResetFatalErrorHandler()
     }
 }
+
+// REVIEW: Should this be exposed publicly? Currently it's only used to capture
+// errors reported through a context's diagnostic handler
+#[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+pub(crate) struct DiagnosticInfo {
+    diagnostic_info: LLVMDiagnosticInfoRef,
+}
+
+#[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+impl DiagnosticInfo {
+    pub(crate) fn new(diagnostic_info: LLVMDiagnosticInfoRef) -> Self {
+        debug_assert!(!diagnostic_info.is_null());
+
+        DiagnosticInfo {
+            diagnostic_info,
+        }
+    }
+
+    pub(crate) fn get_description(&self) -> *mut c_char {
+        unsafe {
+            LLVMGetDiagInfoDescription(self.diagnostic_info)
+        }
+    }
+
+    pub(crate) fn severity_is_error(&self) -> bool {
+        unsafe {
+            match LLVMGetDiagInfoSeverity(self.diagnostic_info) {
+                LLVMDiagnosticSeverity::LLVMDSError => true,
+                _ => false,
+            }
+        }
+    }
+}
+
+// Diagnostic handler which stores the description of the last error it receives
+// into the `*mut *mut c_char` passed in as its context. The description is LLVM
+// allocated and should be wrapped in an `LLVMString` by the caller.
+#[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+pub(crate) extern "C" fn get_error_str_diagnostic_handler(diagnostic_info: LLVMDiagnosticInfoRef, void_ptr: *mut c_void) {
+    let diagnostic_info = DiagnosticInfo::new(diagnostic_info);
+
+    if diagnostic_info.severity_is_error() {
+        let c_str_ptr = void_ptr as *mut *mut c_char;
+
+        unsafe {
+            if !(*c_str_ptr).is_null() {
+                LLVMDisposeMessage(*c_str_ptr);
+            }
+
+            *c_str_ptr = diagnostic_info.get_description();
+        }
+    }
+}
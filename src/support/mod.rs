@@ -1,7 +1,7 @@
 pub mod error_handling;
 
 use libc::c_char;
-use llvm_sys::core::LLVMDisposeMessage;
+use llvm_sys::core::{LLVMCreateMessage, LLVMDisposeMessage};
 use llvm_sys::support::LLVMLoadLibraryPermanently;
 
 use std::error::Error;
@@ -22,6 +22,19 @@ impl LLVMString {
         }
     }
 
+    /// Creates a new `LLVMString` from a Rust string slice. The contents
+    /// are copied into a LLVM allocated buffer so that it may be returned
+    /// alongside strings produced by LLVM itself.
+    pub(crate) fn create(string: &str) -> Self {
+        let c_string = CString::new(string).expect("Conversion to CString failed unexpectedly");
+
+        let ptr = unsafe {
+            LLVMCreateMessage(c_string.as_ptr())
+        };
+
+        LLVMString::new(ptr)
+    }
+
     /// This is a convenience method for creating a Rust `String`,
     /// however; it *will* reallocate. `LLVMString` should be used
     /// as much as possible to save memory since it is allocated by
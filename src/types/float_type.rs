@@ -1,13 +1,13 @@
-use llvm_sys::core::{LLVMConstReal, LLVMConstNull, LLVMHalfType, LLVMFloatType, LLVMDoubleType, LLVMFP128Type, LLVMPPCFP128Type, LLVMConstRealOfStringAndSize};
+use llvm_sys::core::{LLVMConstReal, LLVMConstNull, LLVMHalfType, LLVMFloatType, LLVMDoubleType, LLVMFP128Type, LLVMPPCFP128Type, LLVMConstRealOfStringAndSize, LLVMConstArray};
 use llvm_sys::execution_engine::LLVMCreateGenericValueOfFloat;
-use llvm_sys::prelude::LLVMTypeRef;
+use llvm_sys::prelude::{LLVMTypeRef, LLVMValueRef};
 
 use AddressSpace;
 use context::ContextRef;
 use support::LLVMString;
 use types::traits::AsTypeRef;
 use types::{Type, PointerType, FunctionType, BasicType, ArrayType, VectorType};
-use values::{FloatValue, GenericValue, PointerValue, IntValue};
+use values::{ArrayValue, FloatValue, GenericValue, PointerValue, IntValue};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct FloatType {
@@ -52,6 +52,40 @@ impl FloatType {
         FloatValue::new(value)
     }
 
+    /// Creates a constant array of this `FloatType` from a slice of values, each of which is
+    /// converted to this type, without having to build a `FloatValue` per element first.
+    ///
+    /// This is merely a convenience over `ArrayType::const_array`: the C API has no way of
+    /// taking raw floating point data, so a constant is still created in LLVM per element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` has more than `u32::max_value()` elements.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let f64_type = context.f64_type();
+    /// let f64_array = f64_type.const_array_from_slice(&[1.0, 2.5]);
+    ///
+    /// assert_eq!(f64_array.get_type(), f64_type.array_type(2));
+    /// ```
+    pub fn const_array_from_slice(&self, values: &[f64]) -> ArrayValue {
+        assert!(values.len() <= u32::max_value() as usize, "Array length must be <= u32::max_value()");
+
+        let mut elements: Vec<LLVMValueRef> = values.iter()
+            .map(|&value| unsafe { LLVMConstReal(self.as_type_ref(), value) })
+            .collect();
+
+        let value = unsafe {
+            LLVMConstArray(self.as_type_ref(), elements.as_mut_ptr(), elements.len() as u32)
+        };
+
+        ArrayValue::new(value)
+    }
+
     pub fn const_null_ptr(&self) -> PointerValue {
         self.float_type.const_null_ptr()
     }
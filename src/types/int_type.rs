@@ -1,13 +1,13 @@
-use llvm_sys::core::{LLVMInt1Type, LLVMInt8Type, LLVMInt16Type, LLVMInt32Type, LLVMInt64Type, LLVMConstInt, LLVMConstNull, LLVMConstAllOnes, LLVMIntType, LLVMGetIntTypeWidth, LLVMConstIntOfStringAndSize, LLVMConstIntOfArbitraryPrecision};
+use llvm_sys::core::{LLVMInt1Type, LLVMInt8Type, LLVMInt16Type, LLVMInt32Type, LLVMInt64Type, LLVMConstInt, LLVMConstNull, LLVMConstAllOnes, LLVMIntType, LLVMGetIntTypeWidth, LLVMConstIntOfStringAndSize, LLVMConstIntOfArbitraryPrecision, LLVMConstArray};
 use llvm_sys::execution_engine::LLVMCreateGenericValueOfInt;
-use llvm_sys::prelude::LLVMTypeRef;
+use llvm_sys::prelude::{LLVMTypeRef, LLVMValueRef};
 
 use AddressSpace;
 use context::ContextRef;
 use support::LLVMString;
 use types::traits::AsTypeRef;
 use types::{Type, ArrayType, BasicType, VectorType, PointerType, FunctionType};
-use values::{GenericValue, IntValue, PointerValue};
+use values::{ArrayValue, GenericValue, IntValue, PointerValue};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct IntType {
@@ -219,6 +219,52 @@ impl IntType {
         IntValue::new(value)
     }
 
+    /// Creates a constant array of this `IntType` from a slice of values, without having to
+    /// build an `IntValue` per element first.
+    ///
+    /// Every value is silently truncated to this type's width, so for example 256 becomes
+    /// zero in an i8 array.
+    ///
+    /// Only i8 arrays are handed to LLVM as raw data, through `Context::const_string`. The C
+    /// API has no way of taking raw element data of any other width, so for those a constant
+    /// is still created in LLVM per element and this is merely a convenience over
+    /// `ArrayType::const_array`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` has more than `u32::max_value()` elements.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let i32_type = context.i32_type();
+    /// let i32_array = i32_type.const_array_from_slice(&[1, 2, 3]);
+    ///
+    /// assert_eq!(i32_array.get_type(), i32_type.array_type(3));
+    /// ```
+    pub fn const_array_from_slice(&self, values: &[u64]) -> ArrayValue {
+        assert!(values.len() <= u32::max_value() as usize, "Array length must be <= u32::max_value()");
+
+        // i8 arrays can be copied into LLVM directly as a string. Truncation matches LLVMConstInt
+        if self.get_bit_width() == 8 {
+            let bytes: Vec<u8> = values.iter().map(|&value| value as u8).collect();
+
+            return self.get_context().const_string(&bytes, false);
+        }
+
+        let mut elements: Vec<LLVMValueRef> = values.iter()
+            .map(|&value| unsafe { LLVMConstInt(self.as_type_ref(), value, 0) })
+            .collect();
+
+        let value = unsafe {
+            LLVMConstArray(self.as_type_ref(), elements.as_mut_ptr(), elements.len() as u32)
+        };
+
+        ArrayValue::new(value)
+    }
+
     /// Creates an `IntValue` representing a constant value of all one bits of this `IntType`. It will be automatically assigned this `IntType`'s `Context`.
     ///
     /// # Example
@@ -1,8 +1,9 @@
-use llvm_sys::core::{LLVMIsConstant, LLVMIsAConstantArray, LLVMIsAConstantDataArray};
+use llvm_sys::core::{LLVMIsConstant, LLVMIsAConstantArray, LLVMIsAConstantDataArray, LLVMIsConstantString, LLVMGetAsString};
 use llvm_sys::prelude::LLVMValueRef;
 
 use std::ffi::CStr;
 use std::fmt;
+use std::slice::from_raw_parts;
 
 use support::LLVMString;
 use types::ArrayType;
@@ -70,6 +71,32 @@ impl ArrayValue {
     pub fn replace_all_uses_with(&self, other: &ArrayValue) {
         self.array_value.replace_all_uses_with(other.as_value_ref())
     }
+
+    /// Determines whether this is a constant `[N x i8]` array, such as one created
+    /// by `Context::const_string`.
+    pub fn is_const_string(&self) -> bool {
+        // LLVMIsConstantString expects a ConstantDataSequential, so we must check for one first
+        unsafe {
+            !LLVMIsAConstantDataArray(self.as_value_ref()).is_null() && LLVMIsConstantString(self.as_value_ref()) == 1
+        }
+    }
+
+    /// Gets the contents of a constant `[N x i8]` array, including any null terminator.
+    /// Returns `None` if this isn't a constant string. See `is_const_string`.
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        if !self.is_const_string() {
+            return None;
+        }
+
+        let mut len = 0;
+        let ptr = unsafe {
+            LLVMGetAsString(self.as_value_ref(), &mut len)
+        };
+
+        unsafe {
+            Some(from_raw_parts(ptr as *const u8, len))
+        }
+    }
 }
 
 impl AsValueRef for ArrayValue {
@@ -0,0 +1,163 @@
+use either::Either;
+use llvm_sys::core::{LLVMGetNextUse, LLVMGetUser, LLVMGetUsedValue, LLVMGetNumOperands, LLVMGetOperandUse, LLVMIsAInstruction, LLVMGetTypeKind, LLVMTypeOf};
+use llvm_sys::prelude::{LLVMUseRef, LLVMValueRef};
+use llvm_sys::LLVMTypeKind;
+
+use values::{BasicValueEnum, InstructionValue};
+
+/// A usage of a value by another value, such as an instruction reading it as an operand.
+/// See `AnyValue::get_first_use`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct BasicValueUse {
+    use_value: LLVMUseRef,
+}
+
+impl BasicValueUse {
+    pub(crate) fn new(use_value: LLVMUseRef) -> Option<Self> {
+        if use_value.is_null() {
+            return None;
+        }
+
+        Some(BasicValueUse {
+            use_value,
+        })
+    }
+
+    /// Gets the next use of the same value, if any. Uses are not kept in any particular order.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::values::AnyValue;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let void_type = context.void_type();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    /// let function = module.add_function("my_fn", &fn_type, None);
+    /// let entry = context.append_basic_block(&function, "entry");
+    ///
+    /// builder.position_at_end(&entry);
+    ///
+    /// let ptr = builder.build_alloca(&i32_type, "ptr");
+    ///
+    /// builder.build_store(&ptr, &i32_type.const_int(1, false));
+    /// builder.build_load(&ptr, "value");
+    /// builder.build_return(None);
+    ///
+    /// let mut use_ = ptr.get_first_use();
+    /// let mut count = 0;
+    ///
+    /// while let Some(current_use) = use_ {
+    ///     count += 1;
+    ///     use_ = current_use.get_next_use();
+    /// }
+    ///
+    /// assert_eq!(count, 2);
+    /// ```
+    pub fn get_next_use(&self) -> Option<Self> {
+        let use_value = unsafe {
+            LLVMGetNextUse(self.use_value)
+        };
+
+        BasicValueUse::new(use_value)
+    }
+
+    /// Gets the value making use of the used value. This is usually an `InstructionValue`,
+    /// but may also be a constant such as a constant expression or a global's initializer.
+    /// Returns `None` should the user be neither an instruction nor a basic value.
+    pub fn get_user(&self) -> Option<Either<InstructionValue, BasicValueEnum>> {
+        let user = unsafe {
+            LLVMGetUser(self.use_value)
+        };
+
+        let is_instruction = unsafe {
+            !LLVMIsAInstruction(user).is_null()
+        };
+
+        if is_instruction {
+            return Some(Either::Left(InstructionValue::new(user)));
+        }
+
+        as_basic_value(user).map(Either::Right)
+    }
+
+    /// Gets the value being used, or `None` if it isn't a basic value, such as a label or metadata.
+    pub fn get_used_value(&self) -> Option<BasicValueEnum> {
+        let used_value = unsafe {
+            LLVMGetUsedValue(self.use_value)
+        };
+
+        as_basic_value(used_value)
+    }
+
+    /// Gets the index of the user's operand which refers to the used value. A user may
+    /// refer to the same value through several operands, each of which is a separate use.
+    pub fn get_operand_index(&self) -> u32 {
+        let user = unsafe {
+            LLVMGetUser(self.use_value)
+        };
+
+        let num_operands = unsafe {
+            LLVMGetNumOperands(user) as u32
+        };
+
+        (0..num_operands)
+            .find(|&index| unsafe { LLVMGetOperandUse(user, index) } == self.use_value)
+            .expect("A use should always be one of its user's operands")
+    }
+}
+
+// Values of other types, such as labels, metadata and tokens, can't be a BasicValueEnum
+fn as_basic_value(value: LLVMValueRef) -> Option<BasicValueEnum> {
+    let type_kind = unsafe {
+        LLVMGetTypeKind(LLVMTypeOf(value))
+    };
+
+    match type_kind {
+        LLVMTypeKind::LLVMFloatTypeKind |
+        LLVMTypeKind::LLVMFP128TypeKind |
+        LLVMTypeKind::LLVMDoubleTypeKind |
+        LLVMTypeKind::LLVMHalfTypeKind |
+        LLVMTypeKind::LLVMX86_FP80TypeKind |
+        LLVMTypeKind::LLVMPPC_FP128TypeKind |
+        LLVMTypeKind::LLVMIntegerTypeKind |
+        LLVMTypeKind::LLVMStructTypeKind |
+        LLVMTypeKind::LLVMPointerTypeKind |
+        LLVMTypeKind::LLVMArrayTypeKind |
+        LLVMTypeKind::LLVMVectorTypeKind => Some(BasicValueEnum::new(value)),
+        _ => None,
+    }
+}
+
+/// An iterator over the uses of a value. See `AnyValue::uses`.
+///
+/// The next use is looked up before the current one is yielded, so the yielded use's user
+/// may be erased while iterating, as long as it doesn't also own the next use. A user which
+/// refers to the value through several operands owns several consecutive uses.
+#[derive(Debug)]
+pub struct BasicValueUseIter {
+    next: Option<BasicValueUse>,
+}
+
+impl BasicValueUseIter {
+    pub(crate) fn new(first_use: Option<BasicValueUse>) -> Self {
+        BasicValueUseIter {
+            next: first_use,
+        }
+    }
+}
+
+impl Iterator for BasicValueUseIter {
+    type Item = BasicValueUse;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current_use = self.next?;
+
+        self.next = current_use.get_next_use();
+
+        Some(current_use)
+    }
+}
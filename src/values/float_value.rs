@@ -1,4 +1,4 @@
-use llvm_sys::core::{LLVMConstFNeg, LLVMConstFAdd, LLVMConstFSub, LLVMConstFMul, LLVMConstFDiv, LLVMConstFRem, LLVMConstFPCast, LLVMConstFPToUI, LLVMConstFPToSI, LLVMConstFPTrunc, LLVMConstFPExt, LLVMConstFCmp};
+use llvm_sys::core::{LLVMConstFNeg, LLVMConstFAdd, LLVMConstFSub, LLVMConstFMul, LLVMConstFDiv, LLVMConstFRem, LLVMConstFPCast, LLVMConstFPToUI, LLVMConstFPToSI, LLVMConstFPTrunc, LLVMConstFPExt, LLVMConstFCmp, LLVMConstRealGetDouble, LLVMIsAConstantFP};
 use llvm_sys::prelude::LLVMValueRef;
 
 use std::ffi::CStr;
@@ -168,6 +168,36 @@ impl FloatValue {
         IntValue::new(value)
     }
 
+    /// Gets the value of a constant float as an `f64`, along with whether converting it to an
+    /// `f64` lost any precision (such as for an `fp128`). Returns `None` if this isn't a constant float.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let f32_type = context.f32_type();
+    /// let f32_val = f32_type.const_float(1.5);
+    ///
+    /// assert_eq!(f32_val.get_constant(), Some((1.5, false)));
+    /// ```
+    pub fn get_constant(&self) -> Option<(f64, bool)> {
+        let is_const_float = unsafe {
+            !LLVMIsAConstantFP(self.as_value_ref()).is_null()
+        };
+
+        if !is_const_float {
+            return None;
+        }
+
+        let mut lossy = 0;
+        let constant = unsafe {
+            LLVMConstRealGetDouble(self.as_value_ref(), &mut lossy)
+        };
+
+        Some((constant, lossy == 1))
+    }
+
     pub fn replace_all_uses_with(&self, other: &FloatValue) {
         self.float_value.replace_all_uses_with(other.as_value_ref())
     }
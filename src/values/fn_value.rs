@@ -1,19 +1,25 @@
+#[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+use libc::c_char;
 use llvm_sys::analysis::{LLVMVerifierFailureAction, LLVMVerifyFunction, LLVMViewFunctionCFG, LLVMViewFunctionCFGOnly};
-use llvm_sys::core::{LLVMIsAFunction, LLVMIsConstant, LLVMGetLinkage, LLVMTypeOf, LLVMGetPreviousFunction, LLVMGetNextFunction, LLVMGetParam, LLVMCountParams, LLVMGetLastParam, LLVMCountBasicBlocks, LLVMGetFirstParam, LLVMGetNextParam, LLVMGetBasicBlocks, LLVMGetReturnType, LLVMAppendBasicBlock, LLVMDeleteFunction, LLVMGetElementType, LLVMGetLastBasicBlock, LLVMGetFirstBasicBlock, LLVMGetEntryBasicBlock, LLVMGetIntrinsicID, LLVMGetFunctionCallConv, LLVMSetFunctionCallConv, LLVMGetGC, LLVMSetGC};
+use llvm_sys::core::{LLVMIsAFunction, LLVMIsConstant, LLVMGetLinkage, LLVMTypeOf, LLVMGetPreviousFunction, LLVMGetNextFunction, LLVMGetParam, LLVMCountParams, LLVMGetLastParam, LLVMCountBasicBlocks, LLVMGetFirstParam, LLVMGetNextParam, LLVMGetBasicBlocks, LLVMGetReturnType, LLVMAppendBasicBlock, LLVMDeleteFunction, LLVMGetElementType, LLVMGetLastBasicBlock, LLVMGetFirstBasicBlock, LLVMGetEntryBasicBlock, LLVMGetNextBasicBlock, LLVMGetPreviousBasicBlock, LLVMGetIntrinsicID, LLVMGetFunctionCallConv, LLVMSetFunctionCallConv, LLVMGetGC, LLVMSetGC, LLVMSetLinkage, LLVMGetTypeKind, LLVMGetUndef, LLVMReplaceAllUsesWith, LLVMInstructionEraseFromParent, LLVMDeleteBasicBlock};
+use llvm_sys::LLVMTypeKind;
 #[cfg(not(feature = "llvm3-6"))]
 use llvm_sys::core::{LLVMGetPersonalityFn, LLVMSetPersonalityFn};
 use llvm_sys::prelude::{LLVMValueRef, LLVMBasicBlockRef};
 
 use std::ffi::{CStr, CString};
 use std::mem::forget;
+use std::ptr;
 use std::fmt;
 
-use basic_block::BasicBlock;
+#[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+use attributes::{Attribute, AttributeLoc};
+use basic_block::{BasicBlock, InstructionIter};
 use module::Linkage;
 use support::LLVMString;
 use types::{BasicTypeEnum, FunctionType};
 use values::traits::AsValueRef;
-use values::{BasicValueEnum, Value, MetadataValue};
+use values::{AnyValue, BasicValueEnum, GlobalValue, InstructionValue, Value, MetadataValue};
 
 #[derive(PartialEq, Eq, Clone, Copy)]
 pub struct FunctionValue {
@@ -41,6 +47,12 @@ impl FunctionValue {
         Linkage::new(linkage)
     }
 
+    pub fn set_linkage(&self, linkage: Linkage) {
+        unsafe {
+            LLVMSetLinkage(self.as_value_ref(), linkage.as_llvm_linkage())
+        }
+    }
+
     pub fn is_null(&self) -> bool {
         self.fn_value.is_null()
     }
@@ -207,6 +219,83 @@ impl FunctionValue {
         }
     }
 
+    /// Creates a double-ended iterator over the `BasicBlock`s of this function, in layout order.
+    /// Unlike `get_basic_blocks`, this doesn't allocate.
+    ///
+    /// The iterator moves past each `BasicBlock` before yielding it, so the block just
+    /// yielded may be removed or deleted without disrupting the iteration. Only that block
+    /// is safe to delete, however: both ends hold on to the next block they will yield,
+    /// starting with the first and last blocks, and deleting one of those dangles.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let void_type = context.void_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let entry = fn_value.append_basic_block("entry");
+    /// let exit = fn_value.append_basic_block("exit");
+    ///
+    /// let mut basic_blocks = fn_value.basic_blocks();
+    ///
+    /// assert_eq!(basic_blocks.next(), Some(entry));
+    /// assert_eq!(basic_blocks.next_back(), Some(exit));
+    /// assert!(basic_blocks.next().is_none());
+    /// ```
+    pub fn basic_blocks(&self) -> BasicBlockIter {
+        let (next, next_back) = unsafe {
+            (LLVMGetFirstBasicBlock(self.as_value_ref()), LLVMGetLastBasicBlock(self.as_value_ref()))
+        };
+
+        BasicBlockIter {
+            next,
+            next_back,
+        }
+    }
+
+    /// Creates a double-ended iterator over every `InstructionValue` in this function, visiting
+    /// each `BasicBlock` in layout order. As with `BasicBlock::instructions`, the instruction
+    /// just yielded may be erased or moved without disrupting the iteration.
+    ///
+    /// Nothing else may be erased while iterating. Each end caches the next instruction it
+    /// will yield, including the last instruction of the function for the back end, and
+    /// the next `BasicBlock` it will visit, so erasing any of those, or deleting a block,
+    /// leaves the iterator pointing at freed memory.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::values::InstructionOpcode;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let void_type = context.void_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let entry = fn_value.append_basic_block("entry");
+    /// let exit = fn_value.append_basic_block("exit");
+    ///
+    /// builder.position_at_end(&entry);
+    /// builder.build_unconditional_branch(&exit);
+    /// builder.position_at_end(&exit);
+    /// builder.build_return(None);
+    ///
+    /// let opcodes: Vec<_> = fn_value.instructions().map(|instruction| instruction.get_opcode()).collect();
+    ///
+    /// assert_eq!(opcodes, vec![InstructionOpcode::Br, InstructionOpcode::Return]);
+    /// ```
+    pub fn instructions(&self) -> FunctionInstructionIter {
+        FunctionInstructionIter {
+            basic_blocks: self.basic_blocks(),
+            front: None,
+            back: None,
+        }
+    }
+
     pub fn get_last_basic_block(&self) -> Option<BasicBlock> {
         let bb = unsafe {
             LLVMGetLastBasicBlock(self.fn_value.value)
@@ -231,11 +320,156 @@ impl FunctionValue {
         }
     }
 
-    // FIXME: Look for ways to prevent use after delete
-    pub unsafe fn delete(self) {
-        // unsafe {
-        LLVMDeleteFunction(self.as_value_ref())
-        // }
+    /// Deletes this function, removing it from its `Module`. Once deleted, neither this
+    /// `FunctionValue` nor any copies of it, its params or its basic blocks may be used again.
+    ///
+    /// Returns an error, leaving the function untouched, if it is still used from outside
+    /// of its own body, such as by a call in another function. Recursive calls are fine.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let void_type = context.void_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    ///
+    /// assert!(fn_value.delete().is_ok());
+    /// assert!(module.get_function("my_fn").is_none());
+    /// ```
+    pub fn delete(self) -> Result<(), LLVMString> {
+        if self.has_external_uses() {
+            return Err(LLVMString::create("Cannot delete a function which is still used outside of its body"));
+        }
+
+        unsafe {
+            LLVMDeleteFunction(self.as_value_ref())
+        }
+
+        Ok(())
+    }
+
+    /// Deletes every basic block of this function, turning it back into a declaration to
+    /// which a new body may be appended. Any personality function is removed, except before
+    /// LLVM 3.9, which has no safe way to check for one.
+    ///
+    /// As with `Function::deleteBody` in LLVM, the linkage is reset to `Linkage::ExternalLinkage`,
+    /// since a declaration with private or internal linkage fails verification. This makes
+    /// the function visible outside of its module, so the previous linkage is returned in
+    /// order for it to be restored once a new body has been added.
+    ///
+    /// Any `BasicBlock`s or `InstructionValue`s previously obtained from this function
+    /// must not be used again.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::module::Linkage;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let void_type = context.void_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, Some(&Linkage::InternalLinkage));
+    /// let entry = fn_value.append_basic_block("entry");
+    ///
+    /// builder.position_at_end(&entry);
+    /// builder.build_return(None);
+    ///
+    /// let linkage = fn_value.clear_body();
+    ///
+    /// assert_eq!(fn_value.count_basic_blocks(), 0);
+    /// assert_eq!(linkage, Linkage::InternalLinkage);
+    /// assert_eq!(fn_value.get_linkage(), Linkage::ExternalLinkage);
+    /// assert!(module.verify().is_ok());
+    ///
+    /// let entry = fn_value.append_basic_block("entry");
+    ///
+    /// builder.position_at_end(&entry);
+    /// builder.build_return(None);
+    /// fn_value.set_linkage(linkage);
+    ///
+    /// assert!(module.verify().is_ok());
+    /// ```
+    pub fn clear_body(&self) -> Linkage {
+        let linkage = self.get_linkage();
+        let basic_blocks = self.get_basic_blocks();
+
+        // Instructions may refer to each other across blocks, so every reference has to be
+        // dropped before anything is deleted
+        for basic_block in &basic_blocks {
+            let mut instruction = basic_block.get_first_instruction();
+
+            while let Some(current_instruction) = instruction {
+                let value = current_instruction.as_value_ref();
+
+                unsafe {
+                    let type_ = LLVMTypeOf(value);
+
+                    if LLVMGetTypeKind(type_) != LLVMTypeKind::LLVMVoidTypeKind {
+                        LLVMReplaceAllUsesWith(value, LLVMGetUndef(type_));
+                    }
+                }
+
+                instruction = current_instruction.get_next_instruction();
+            }
+        }
+
+        for basic_block in &basic_blocks {
+            while let Some(instruction) = basic_block.get_last_instruction() {
+                unsafe {
+                    LLVMInstructionEraseFromParent(instruction.as_value_ref())
+                }
+            }
+        }
+
+        for basic_block in basic_blocks {
+            unsafe {
+                LLVMDeleteBasicBlock(basic_block.basic_block)
+            }
+        }
+
+        unsafe {
+            LLVMSetLinkage(self.as_value_ref(), Linkage::ExternalLinkage.as_llvm_linkage())
+        }
+
+        // Clearing a personality which isn't there trips an assertion in LLVM. LLVMGetPersonalityFn
+        // can't be used to check for one either, as it reads the missing operand regardless
+        #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+        {
+            if self.has_personality_function() {
+                unsafe {
+                    LLVMSetPersonalityFn(self.as_value_ref(), ptr::null_mut())
+                }
+            }
+        }
+
+        linkage
+    }
+
+    /// Consumes this function and returns it as a declaration. See `clear_body`, which
+    /// this calls. Note that the linkage is reset to `Linkage::ExternalLinkage`, and the
+    /// previous linkage is discarded; use `clear_body` directly to keep it.
+    pub fn into_declaration(self) -> Self {
+        self.clear_body();
+
+        self
+    }
+
+    // Determines whether this function is used by anything other than its own instructions
+    fn has_external_uses(&self) -> bool {
+        self.uses().any(|use_| {
+            let is_internal = use_.get_user()
+                .and_then(|user| user.left())
+                .and_then(|instruction| instruction.get_parent())
+                .and_then(|basic_block| basic_block.get_parent())
+                .map_or(false, |parent| parent == *self);
+
+            !is_internal
+        })
     }
 
     pub fn get_type(&self) -> FunctionType {
@@ -312,6 +546,183 @@ impl FunctionValue {
         }
     }
 
+    /// Adds an `Attribute` to this function's return type, one of its params, or the function itself.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::attributes::{Attribute, AttributeLoc};
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let void_type = context.void_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let noinline = context.create_enum_attribute(Attribute::get_named_enum_kind_id("noinline"), 0);
+    /// let target_cpu = context.create_string_attribute("target-cpu", "x86-64");
+    ///
+    /// fn_value.add_attribute(AttributeLoc::Function, noinline);
+    /// fn_value.add_attribute(AttributeLoc::Function, target_cpu);
+    /// ```
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+    pub fn add_attribute(&self, loc: AttributeLoc, attribute: Attribute) {
+        use llvm_sys::core::LLVMAddAttributeAtIndex;
+
+        unsafe {
+            LLVMAddAttributeAtIndex(self.as_value_ref(), loc.get_index(), attribute.attribute)
+        }
+    }
+
+    /// Counts the `Attribute`s at the given location. Unlike the other attribute methods,
+    /// this requires LLVM 4.0 or later.
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8", feature = "llvm3-9")))]
+    pub fn count_attributes(&self, loc: AttributeLoc) -> u32 {
+        use llvm_sys::core::LLVMGetAttributeCountAtIndex;
+
+        unsafe {
+            LLVMGetAttributeCountAtIndex(self.as_value_ref(), loc.get_index())
+        }
+    }
+
+    /// Gets all of the `Attribute`s at the given location. Unlike the other attribute methods,
+    /// this requires LLVM 4.0 or later.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::AddressSpace;
+    /// use inkwell::attributes::{Attribute, AttributeLoc};
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let void_type = context.void_type();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = void_type.fn_type(&[&i32_type.ptr_type(AddressSpace::Generic)], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let sret = context.create_enum_attribute(Attribute::get_named_enum_kind_id("sret"), 0);
+    ///
+    /// fn_value.add_attribute(AttributeLoc::Param(0), sret);
+    ///
+    /// assert_eq!(fn_value.get_attributes(AttributeLoc::Param(0)), vec![sret]);
+    /// assert!(fn_value.get_attributes(AttributeLoc::Return).is_empty());
+    /// ```
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8", feature = "llvm3-9")))]
+    pub fn get_attributes(&self, loc: AttributeLoc) -> Vec<Attribute> {
+        use llvm_sys::core::LLVMGetAttributesAtIndex;
+
+        let count = self.count_attributes(loc) as usize;
+        let mut attributes = Vec::with_capacity(count);
+
+        unsafe {
+            LLVMGetAttributesAtIndex(self.as_value_ref(), loc.get_index(), attributes.as_mut_ptr());
+
+            attributes.set_len(count);
+        }
+
+        attributes.into_iter().map(Attribute::new).collect()
+    }
+
+    /// Gets the enum `Attribute` with the given kind id at the given location, if any.
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+    pub fn get_enum_attribute(&self, loc: AttributeLoc, kind_id: u32) -> Option<Attribute> {
+        use llvm_sys::core::LLVMGetEnumAttributeAtIndex;
+
+        let attribute = unsafe {
+            LLVMGetEnumAttributeAtIndex(self.as_value_ref(), loc.get_index(), kind_id)
+        };
+
+        if attribute.is_null() {
+            return None;
+        }
+
+        Some(Attribute::new(attribute))
+    }
+
+    /// Gets the string `Attribute` with the given key at the given location, if any.
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+    pub fn get_string_attribute(&self, loc: AttributeLoc, key: &str) -> Option<Attribute> {
+        use llvm_sys::core::LLVMGetStringAttributeAtIndex;
+
+        let attribute = unsafe {
+            LLVMGetStringAttributeAtIndex(self.as_value_ref(), loc.get_index(), key.as_ptr() as *const c_char, key.len() as u32)
+        };
+
+        if attribute.is_null() {
+            return None;
+        }
+
+        Some(Attribute::new(attribute))
+    }
+
+    /// Removes `attribute` from the given location, if present. Enum attributes are matched
+    /// by their kind id and string attributes by their key, regardless of value.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::attributes::{Attribute, AttributeLoc};
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let void_type = context.void_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let noinline = context.create_enum_attribute(Attribute::get_named_enum_kind_id("noinline"), 0);
+    ///
+    /// fn_value.add_attribute(AttributeLoc::Function, noinline);
+    /// fn_value.remove_attribute(AttributeLoc::Function, noinline);
+    ///
+    /// assert!(fn_value.get_enum_attribute(AttributeLoc::Function, noinline.get_enum_kind_id()).is_none());
+    /// ```
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+    pub fn remove_attribute(&self, loc: AttributeLoc, attribute: Attribute) {
+        use llvm_sys::core::{LLVMRemoveEnumAttributeAtIndex, LLVMRemoveStringAttributeAtIndex};
+
+        if attribute.is_enum() {
+            unsafe {
+                LLVMRemoveEnumAttributeAtIndex(self.as_value_ref(), loc.get_index(), attribute.get_enum_kind_id())
+            }
+        } else {
+            let key = attribute.get_string_kind_id().to_bytes();
+
+            unsafe {
+                LLVMRemoveStringAttributeAtIndex(self.as_value_ref(), loc.get_index(), key.as_ptr() as *const c_char, key.len() as u32)
+            }
+        }
+    }
+
+    /// Removes the enum `Attribute` with the given kind id from the given location, if present.
+    /// Useful when only the kind is known; see `remove_attribute`.
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+    pub fn remove_enum_attribute(&self, loc: AttributeLoc, kind_id: u32) {
+        use llvm_sys::core::LLVMRemoveEnumAttributeAtIndex;
+
+        unsafe {
+            LLVMRemoveEnumAttributeAtIndex(self.as_value_ref(), loc.get_index(), kind_id)
+        }
+    }
+
+    /// Removes the string `Attribute` with the given key from the given location, if present.
+    /// Useful when only the key is known; see `remove_attribute`.
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+    pub fn remove_string_attribute(&self, loc: AttributeLoc, key: &str) {
+        use llvm_sys::core::LLVMRemoveStringAttributeAtIndex;
+
+        unsafe {
+            LLVMRemoveStringAttributeAtIndex(self.as_value_ref(), loc.get_index(), key.as_ptr() as *const c_char, key.len() as u32)
+        }
+    }
+
+    /// Gets a `GlobalValue` view of this function, which gives access to properties
+    /// shared by all globals such as its section and visibility. Global variable only
+    /// properties, such as an initializer, are unavailable through it.
+    pub fn as_global_value(&self) -> GlobalValue {
+        GlobalValue::new(self.as_value_ref())
+    }
+
     pub fn replace_all_uses_with(&self, other: &FunctionValue) {
         self.fn_value.replace_all_uses_with(other.as_value_ref())
     }
@@ -383,3 +794,97 @@ impl Iterator for ParamValueIter {
         Some(BasicValueEnum::new(next_value))
     }
 }
+
+/// A double-ended iterator over the `BasicBlock`s of a `FunctionValue`. See `FunctionValue::basic_blocks`.
+#[derive(Debug)]
+pub struct BasicBlockIter {
+    // BasicBlock isn't Copy, so the raw references are kept instead. Null marks an exhausted end
+    next: LLVMBasicBlockRef,
+    next_back: LLVMBasicBlockRef,
+}
+
+impl Iterator for BasicBlockIter {
+    type Item = BasicBlock;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let basic_block = self.next;
+
+        if basic_block.is_null() {
+            return None;
+        }
+
+        // Both ends have met, so there is nothing left to yield
+        if basic_block == self.next_back {
+            self.next = ptr::null_mut();
+            self.next_back = ptr::null_mut();
+        } else {
+            self.next = unsafe {
+                LLVMGetNextBasicBlock(basic_block)
+            };
+        }
+
+        BasicBlock::new(basic_block)
+    }
+}
+
+impl DoubleEndedIterator for BasicBlockIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let basic_block = self.next_back;
+
+        if basic_block.is_null() {
+            return None;
+        }
+
+        if basic_block == self.next {
+            self.next = ptr::null_mut();
+            self.next_back = ptr::null_mut();
+        } else {
+            self.next_back = unsafe {
+                LLVMGetPreviousBasicBlock(basic_block)
+            };
+        }
+
+        BasicBlock::new(basic_block)
+    }
+}
+
+/// A double-ended iterator over every `InstructionValue` in a `FunctionValue`. See `FunctionValue::instructions`.
+#[derive(Debug)]
+pub struct FunctionInstructionIter {
+    basic_blocks: BasicBlockIter,
+    front: Option<InstructionIter>,
+    back: Option<InstructionIter>,
+}
+
+impl Iterator for FunctionInstructionIter {
+    type Item = InstructionValue;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(instruction) = self.front.as_mut().and_then(|front| front.next()) {
+                return Some(instruction);
+            }
+
+            match self.basic_blocks.next() {
+                Some(basic_block) => self.front = Some(basic_block.instructions()),
+                // The back iterator may still hold the remainder of the last block
+                None => return self.back.as_mut().and_then(|back| back.next()),
+            }
+        }
+    }
+}
+
+impl DoubleEndedIterator for FunctionInstructionIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(instruction) = self.back.as_mut().and_then(|back| back.next_back()) {
+                return Some(instruction);
+            }
+
+            match self.basic_blocks.next_back() {
+                Some(basic_block) => self.back = Some(basic_block.instructions()),
+                None => return self.front.as_mut().and_then(|front| front.next_back()),
+            }
+        }
+    }
+}
@@ -1,13 +1,22 @@
 use llvm_sys::LLVMThreadLocalMode;
-use llvm_sys::core::{LLVMGetVisibility, LLVMSetVisibility, LLVMGetSection, LLVMSetSection, LLVMIsExternallyInitialized, LLVMSetExternallyInitialized, LLVMDeleteGlobal, LLVMIsGlobalConstant, LLVMSetGlobalConstant, LLVMGetPreviousGlobal, LLVMGetNextGlobal, LLVMHasUnnamedAddr, LLVMSetUnnamedAddr, LLVMIsThreadLocal, LLVMSetThreadLocal, LLVMGetThreadLocalMode, LLVMSetThreadLocalMode, LLVMGetInitializer, LLVMSetInitializer, LLVMIsDeclaration, LLVMGetDLLStorageClass, LLVMSetDLLStorageClass};
+use llvm_sys::core::{LLVMGetVisibility, LLVMSetVisibility, LLVMGetSection, LLVMSetSection, LLVMIsExternallyInitialized, LLVMSetExternallyInitialized, LLVMDeleteGlobal, LLVMIsGlobalConstant, LLVMSetGlobalConstant, LLVMGetPreviousGlobal, LLVMGetNextGlobal, LLVMHasUnnamedAddr, LLVMSetUnnamedAddr, LLVMIsThreadLocal, LLVMSetThreadLocal, LLVMGetThreadLocalMode, LLVMSetThreadLocalMode, LLVMGetInitializer, LLVMSetInitializer, LLVMIsDeclaration, LLVMGetDLLStorageClass, LLVMSetDLLStorageClass, LLVMIsAGlobalAlias, LLVMIsAGlobalObject, LLVMIsAGlobalVariable, LLVMGetOperand, LLVMSetOperand, LLVMGetLinkage, LLVMSetLinkage, LLVMGetAlignment, LLVMSetAlignment, LLVMGetPointerAddressSpace, LLVMTypeOf, LLVMGetElementType};
 use llvm_sys::prelude::LLVMValueRef;
 
 use std::ffi::{CString, CStr};
 
 use {GlobalVisibility, ThreadLocalMode, DLLStorageClass};
+use module::Linkage;
+use support::LLVMString;
+use types::AnyTypeEnum;
 use values::traits::AsValueRef;
-use values::{BasicValueEnum, BasicValue, PointerValue, Value};
-
+use values::{AnyValue, BasicValueEnum, BasicValue, PointerValue, Value};
+
+/// A global variable, function or alias of a `Module`.
+///
+/// Some operations only make sense for global variables, such as initializers, constness and
+/// thread locality. On a function or alias, their getters return `None` or `false`, while their
+/// setters do nothing. Likewise, aliases have neither an alignment nor a section of their own,
+/// so setting either on an alias does nothing.
 // REVIEW: GlobalValues are always PointerValues. With SubTypes, we should
 // compress this into a PointerValue<Global> type
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -24,7 +33,20 @@ impl GlobalValue {
         }
     }
 
+    pub fn get_name(&self) -> &CStr {
+        self.global_value.get_name()
+    }
+
+    pub fn set_name(&self, name: &str) {
+        self.global_value.set_name(name)
+    }
+
+    // Functions and aliases aren't part of the module's global variable list
     pub fn get_previous_global(&self) -> Option<GlobalValue> {
+        if !self.is_global_variable() {
+            return None;
+        }
+
         let value = unsafe {
             LLVMGetPreviousGlobal(self.as_value_ref())
         };
@@ -37,6 +59,10 @@ impl GlobalValue {
     }
 
     pub fn get_next_global(&self) -> Option<GlobalValue> {
+        if !self.is_global_variable() {
+            return None;
+        }
+
         let value = unsafe {
             LLVMGetNextGlobal(self.as_value_ref())
         };
@@ -63,6 +89,10 @@ impl GlobalValue {
     }
 
     pub fn get_initializer(&self) -> Option<BasicValueEnum> {
+        if !self.is_global_variable() {
+            return None;
+        }
+
         let value = unsafe {
             LLVMGetInitializer(self.as_value_ref())
         };
@@ -76,25 +106,37 @@ impl GlobalValue {
 
     // SubType: This input type should be tied to the BasicType
     pub fn set_initializer(&self, value: &BasicValue) {
+        if !self.is_global_variable() {
+            return;
+        }
+
         unsafe {
             LLVMSetInitializer(self.as_value_ref(), value.as_value_ref())
         }
     }
 
     pub fn is_thread_local(&self) -> bool {
-        unsafe {
+        self.is_global_variable() && unsafe {
             LLVMIsThreadLocal(self.as_value_ref()) == 1
         }
     }
 
     // TODOC: Setting this to true is the same as setting GeneralDynamicTLSModel
     pub fn set_thread_local(&self, is_thread_local: bool) {
+        if !self.is_global_variable() {
+            return;
+        }
+
         unsafe {
             LLVMSetThreadLocal(self.as_value_ref(), is_thread_local as i32)
         }
     }
 
     pub fn get_thread_local_mode(&self) -> Option<ThreadLocalMode> {
+        if !self.is_global_variable() {
+            return None;
+        }
+
         let thread_local_mode = unsafe {
             LLVMGetThreadLocalMode(self.as_value_ref())
         };
@@ -105,6 +147,10 @@ impl GlobalValue {
     // REVIEW: Does this have any bad behavior if it isn't thread local or just a noop?
     // or should it call self.set_thread_local(true)?
     pub fn set_thread_local_mode(&self, thread_local_mode: Option<ThreadLocalMode>) {
+        if !self.is_global_variable() {
+            return;
+        }
+
         let thread_local_mode = match thread_local_mode {
             Some(mode) => mode.as_llvm_mode(),
             None => LLVMThreadLocalMode::LLVMNotThreadLocal,
@@ -135,24 +181,32 @@ impl GlobalValue {
     }
 
     pub fn is_constant(&self) -> bool {
-        unsafe {
+        self.is_global_variable() && unsafe {
             LLVMIsGlobalConstant(self.as_value_ref()) == 1
         }
     }
 
     pub fn set_constant(&self, is_constant: bool) {
+        if !self.is_global_variable() {
+            return;
+        }
+
         unsafe {
             LLVMSetGlobalConstant(self.as_value_ref(), is_constant as i32)
         }
     }
 
     pub fn is_externally_initialized(&self) -> bool {
-        unsafe {
+        self.is_global_variable() && unsafe {
             LLVMIsExternallyInitialized(self.as_value_ref()) == 1
         }
     }
 
     pub fn set_externally_initialized(&self, externally_initialized: bool) {
+        if !self.is_global_variable() {
+            return;
+        }
+
         unsafe {
             LLVMSetExternallyInitialized(self.as_value_ref(), externally_initialized as i32)
         }
@@ -179,6 +233,10 @@ impl GlobalValue {
     }
 
     pub fn set_section(&self, section: &str) {
+        if !self.is_global_object() {
+            return;
+        }
+
         let c_string = CString::new(section).expect("Conversion to CString failed unexpectedly");
 
         unsafe {
@@ -186,8 +244,200 @@ impl GlobalValue {
         }
     }
 
-    pub unsafe fn delete(self) {
-        LLVMDeleteGlobal(self.as_value_ref())
+    // Global variables and functions, but not aliases
+    fn is_global_object(&self) -> bool {
+        unsafe {
+            !LLVMIsAGlobalObject(self.as_value_ref()).is_null()
+        }
+    }
+
+    fn is_global_variable(&self) -> bool {
+        unsafe {
+            !LLVMIsAGlobalVariable(self.as_value_ref()).is_null()
+        }
+    }
+
+    /// Determines whether or not this `GlobalValue` is an alias of another global.
+    /// See `Module::add_global_alias`.
+    pub fn is_alias(&self) -> bool {
+        unsafe {
+            !LLVMIsAGlobalAlias(self.as_value_ref()).is_null()
+        }
+    }
+
+    /// Gets the value aliased by this `GlobalValue`, or `None` if it is not an alias.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let i32_type = context.i32_type();
+    /// let global = module.add_global(&i32_type, None, "my_global");
+    /// let alias = module.add_global_alias(&i32_type, &global.as_pointer_value(), "my_alias");
+    ///
+    /// assert_eq!(alias.get_aliasee(), Some(global.as_pointer_value()));
+    /// assert!(global.get_aliasee().is_none());
+    /// ```
+    // An alias' only operand is its aliasee. LLVMAliasGetAliasee is only available from LLVM 7.0
+    pub fn get_aliasee(&self) -> Option<PointerValue> {
+        if !self.is_alias() {
+            return None;
+        }
+
+        let value = unsafe {
+            LLVMGetOperand(self.as_value_ref(), 0)
+        };
+
+        Some(PointerValue::new(value))
+    }
+
+    /// Points this alias at a new global, function or constant expression thereof, returning
+    /// whether or not it succeeded. The aliasee must have the same pointer type as this alias,
+    /// and nothing is changed if this `GlobalValue` is not an alias.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let i32_type = context.i32_type();
+    /// let i64_type = context.i64_type();
+    /// let global = module.add_global(&i32_type, None, "my_global");
+    /// let global2 = module.add_global(&i32_type, None, "my_global2");
+    /// let global3 = module.add_global(&i64_type, None, "my_global3");
+    /// let alias = module.add_global_alias(&i32_type, &global.as_pointer_value(), "my_alias");
+    ///
+    /// assert!(alias.set_aliasee(&global2.as_pointer_value()));
+    /// assert!(!alias.set_aliasee(&global3.as_pointer_value()));
+    /// assert!(!global.set_aliasee(&global2.as_pointer_value()));
+    /// ```
+    pub fn set_aliasee(&self, aliasee: &PointerValue) -> bool {
+        let same_type = unsafe {
+            LLVMTypeOf(self.as_value_ref()) == LLVMTypeOf(aliasee.as_value_ref())
+        };
+
+        if !self.is_alias() || !same_type {
+            return false;
+        }
+
+        unsafe {
+            LLVMSetOperand(self.as_value_ref(), 0, aliasee.as_value_ref())
+        }
+
+        true
+    }
+
+    /// Gets the `Linkage` of this `GlobalValue`.
+    pub fn get_linkage(&self) -> Linkage {
+        let linkage = unsafe {
+            LLVMGetLinkage(self.as_value_ref())
+        };
+
+        Linkage::new(linkage)
+    }
+
+    /// Sets the `Linkage` of this `GlobalValue`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::module::Linkage;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let i32_type = context.i32_type();
+    /// let global = module.add_global(&i32_type, None, "my_global");
+    ///
+    /// assert_eq!(global.get_linkage(), Linkage::ExternalLinkage);
+    ///
+    /// global.set_linkage(Linkage::InternalLinkage);
+    ///
+    /// assert_eq!(global.get_linkage(), Linkage::InternalLinkage);
+    /// ```
+    pub fn set_linkage(&self, linkage: Linkage) {
+        unsafe {
+            LLVMSetLinkage(self.as_value_ref(), linkage.as_llvm_linkage())
+        }
+    }
+
+    /// Gets the alignment of this `GlobalValue` in bytes, where zero means the target's default alignment is used.
+    pub fn get_alignment(&self) -> u32 {
+        if !self.is_global_object() {
+            return 0;
+        }
+
+        unsafe {
+            LLVMGetAlignment(self.as_value_ref())
+        }
+    }
+
+    /// Sets the alignment of this `GlobalValue` in bytes, which must be zero or a power of two.
+    /// Does nothing on an alias.
+    pub fn set_alignment(&self, alignment: u32) {
+        if !self.is_global_object() {
+            return;
+        }
+
+        unsafe {
+            LLVMSetAlignment(self.as_value_ref(), alignment)
+        }
+    }
+
+    /// Gets the number of the address space this `GlobalValue` resides in. Address spaces
+    /// are target specific, so this may be any number rather than just those `AddressSpace`
+    /// has variants for. Compare against `AddressSpace::Const as u32` and so on.
+    pub fn get_address_space(&self) -> u32 {
+        unsafe {
+            LLVMGetPointerAddressSpace(LLVMTypeOf(self.as_value_ref()))
+        }
+    }
+
+    /// Gets the type of the value held by this `GlobalValue`. Since the `GlobalValue` itself
+    /// is always a pointer, this is the type it points to, such as a `FunctionType` for functions.
+    // REVIEW: LLVMGlobalGetValueType is only available from LLVM 8.0 onwards
+    pub fn get_value_type(&self) -> AnyTypeEnum {
+        let type_ = unsafe {
+            LLVMGetElementType(LLVMTypeOf(self.as_value_ref()))
+        };
+
+        AnyTypeEnum::new(type_)
+    }
+
+    /// Deletes this `GlobalValue`, removing it from its `Module`. Once deleted, neither this
+    /// `GlobalValue` nor any copies of it may be used again.
+    ///
+    /// Returns an error, leaving the `GlobalValue` untouched, if it still has uses or if it is
+    /// not a global variable. Functions should be deleted with `FunctionValue::delete` instead.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let i32_type = context.i32_type();
+    /// let global = module.add_global(&i32_type, None, "my_global");
+    ///
+    /// assert!(global.delete().is_ok());
+    /// assert!(module.get_global("my_global").is_none());
+    /// ```
+    pub fn delete(self) -> Result<(), LLVMString> {
+        if !self.is_global_variable() {
+            return Err(LLVMString::create("Only global variables can be deleted as a GlobalValue"));
+        }
+
+        if self.get_first_use().is_some() {
+            return Err(LLVMString::create("Cannot delete a global which still has uses"));
+        }
+
+        unsafe {
+            LLVMDeleteGlobal(self.as_value_ref())
+        }
+
+        Ok(())
     }
 
     pub fn as_pointer_value(&self) -> PointerValue {
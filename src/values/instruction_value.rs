@@ -1,15 +1,18 @@
-use llvm_sys::core::{LLVMGetInstructionOpcode, LLVMIsTailCall, LLVMGetPreviousInstruction, LLVMGetNextInstruction, LLVMGetInstructionParent, LLVMInstructionEraseFromParent, LLVMInstructionClone, LLVMSetVolatile, LLVMGetVolatile};
-use llvm_sys::LLVMOpcode;
+use either::Either;
+use llvm_sys::core::{LLVMGetInstructionOpcode, LLVMIsTailCall, LLVMGetPreviousInstruction, LLVMGetNextInstruction, LLVMGetInstructionParent, LLVMInstructionEraseFromParent, LLVMInstructionClone, LLVMSetVolatile, LLVMGetVolatile, LLVMGetNumOperands, LLVMGetOperand, LLVMSetOperand, LLVMValueIsBasicBlock, LLVMValueAsBasicBlock, LLVMGetTypeKind, LLVMTypeOf, LLVMInstructionRemoveFromParent, LLVMIsATerminatorInst, LLVMBasicBlockAsValue};
+use llvm_sys::{LLVMOpcode, LLVMTypeKind};
 use llvm_sys::prelude::LLVMValueRef;
 
 use basic_block::BasicBlock;
+use builder::Builder;
+use support::LLVMString;
 use values::traits::AsValueRef;
-use values::Value;
+use values::{AllocaInst, AnyValue, BasicValue, BasicValueEnum, BranchInst, CallInst, FCmpInst, ICmpInst, LoadInst, PhiValue, StoreInst, Value};
 
 // REVIEW: Split up into structs for SubTypes on InstructionValues?
 // REVIEW: This should maybe be split up into InstructionOpcode and ConstOpcode?
 // see LLVMGetConstOpcode
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
 pub enum InstructionOpcode {
     // Actual Instructions:
     Add,
@@ -283,6 +286,117 @@ impl InstructionValue {
         Some(InstructionValue::new(value))
     }
 
+    /// Erases this instruction from its basic block and deletes it. Once erased, neither this
+    /// `InstructionValue` nor any copies of it may be used again.
+    ///
+    /// Returns an error, leaving the instruction untouched, if it still has uses or is not
+    /// in a basic block. Detached instructions are erased with `DetachedInstruction::erase`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let void_type = context.void_type();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    /// let function = module.add_function("my_fn", &fn_type, None);
+    /// let entry = context.append_basic_block(&function, "entry");
+    ///
+    /// builder.position_at_end(&entry);
+    ///
+    /// let ptr = builder.build_alloca(&i32_type, "ptr");
+    /// let store = builder.build_store(&ptr, &i32_type.const_int(1, false));
+    /// let alloca = ptr.as_instruction().unwrap();
+    ///
+    /// assert!(alloca.erase().is_err());
+    /// assert!(store.erase().is_ok());
+    /// assert!(alloca.erase().is_ok());
+    /// ```
+    pub fn erase(self) -> Result<(), LLVMString> {
+        if self.get_first_use().is_some() {
+            return Err(LLVMString::create("Cannot erase an instruction which still has uses"));
+        }
+
+        if self.get_parent().is_none() {
+            return Err(LLVMString::create("Cannot erase an instruction which is not in a basic block"));
+        }
+
+        unsafe {
+            LLVMInstructionEraseFromParent(self.as_value_ref())
+        }
+
+        Ok(())
+    }
+
+    /// Unlinks this instruction from its basic block without deleting it. The returned
+    /// `DetachedInstruction` must either be reinserted with `Builder::insert_detached_instruction`
+    /// or deleted with `DetachedInstruction::erase`, as LLVM does not free it otherwise.
+    ///
+    /// Returns `None` if the instruction is not in a basic block.
+    pub fn detach(self) -> Option<DetachedInstruction> {
+        if self.get_parent().is_none() {
+            return None;
+        }
+
+        unsafe {
+            LLVMInstructionRemoveFromParent(self.as_value_ref())
+        }
+
+        Some(DetachedInstruction {
+            instruction: self,
+        })
+    }
+
+    /// Moves this instruction so that it directly precedes `instruction`, which may be in
+    /// another basic block. The C API can only insert instructions through a `Builder`, so
+    /// `builder` is used to reinsert this one and is left positioned directly after it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either instruction is not in a basic block.
+    pub fn move_before(&self, instruction: &InstructionValue, builder: &Builder) {
+        assert!(instruction.get_parent().is_some(), "Cannot move an instruction next to one which is not in a basic block");
+
+        if self == instruction {
+            return;
+        }
+
+        let detached = self.detach().expect("Cannot move an instruction which is not in a basic block");
+
+        builder.position_before(instruction);
+        builder.insert_detached_instruction(detached);
+    }
+
+    /// Moves this instruction so that it directly follows `instruction`, which may be in
+    /// another basic block. The C API can only insert instructions through a `Builder`, so
+    /// `builder` is used to reinsert this one and is left positioned directly after it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either instruction is not in a basic block.
+    pub fn move_after(&self, instruction: &InstructionValue, builder: &Builder) {
+        let parent = instruction.get_parent().expect("Cannot move an instruction next to one which is not in a basic block");
+
+        if self == instruction {
+            return;
+        }
+
+        let detached = self.detach().expect("Cannot move an instruction which is not in a basic block");
+
+        // Only looked up once detached, in case we were the next instruction ourselves
+        match instruction.get_next_instruction() {
+            Some(ref next_instruction) => builder.position_before(next_instruction),
+            None => builder.position_at_end(&parent),
+        }
+
+        builder.insert_detached_instruction(detached);
+    }
+
+    /// Erases this instruction from its basic block and deletes it, regardless of whether it
+    /// still has uses. Prefer `erase`, which checks for remaining uses first.
     // REVIEW: Potentially unsafe if parent BB or grandparent fn were removed?
     // REVIEW: Is this actually an erase and should be (self)?
     pub fn remove_from_basic_block(&self) {
@@ -315,6 +429,78 @@ impl InstructionValue {
         self.instruction_value.replace_all_uses_with(other.as_value_ref())
     }
 
+    /// Gets a typed view of this instruction if it is an `alloca`.
+    pub fn as_alloca(&self) -> Option<AllocaInst> {
+        if self.get_opcode() != InstructionOpcode::Alloca {
+            return None;
+        }
+
+        Some(AllocaInst::new(*self))
+    }
+
+    /// Gets a typed view of this instruction if it is a `load`.
+    pub fn as_load(&self) -> Option<LoadInst> {
+        if self.get_opcode() != InstructionOpcode::Load {
+            return None;
+        }
+
+        Some(LoadInst::new(*self))
+    }
+
+    /// Gets a typed view of this instruction if it is a `store`.
+    pub fn as_store(&self) -> Option<StoreInst> {
+        if self.get_opcode() != InstructionOpcode::Store {
+            return None;
+        }
+
+        Some(StoreInst::new(*self))
+    }
+
+    /// Gets a typed view of this instruction if it is a `call`.
+    pub fn as_call(&self) -> Option<CallInst> {
+        if self.get_opcode() != InstructionOpcode::Call {
+            return None;
+        }
+
+        Some(CallInst::new(*self))
+    }
+
+    /// Gets a typed view of this instruction if it is an `icmp`.
+    pub fn as_icmp(&self) -> Option<ICmpInst> {
+        if self.get_opcode() != InstructionOpcode::ICmp {
+            return None;
+        }
+
+        Some(ICmpInst::new(*self))
+    }
+
+    /// Gets a typed view of this instruction if it is an `fcmp`.
+    pub fn as_fcmp(&self) -> Option<FCmpInst> {
+        if self.get_opcode() != InstructionOpcode::FCmp {
+            return None;
+        }
+
+        Some(FCmpInst::new(*self))
+    }
+
+    /// Gets a typed view of this instruction if it is a `br`.
+    pub fn as_branch(&self) -> Option<BranchInst> {
+        if self.get_opcode() != InstructionOpcode::Br {
+            return None;
+        }
+
+        Some(BranchInst::new(*self))
+    }
+
+    /// Gets a typed view of this instruction if it is a `phi`.
+    pub fn as_phi(&self) -> Option<PhiValue> {
+        if self.get_opcode() != InstructionOpcode::Phi {
+            return None;
+        }
+
+        Some(PhiValue::new(self.as_value_ref()))
+    }
+
     // SubTypes: Only apply to memory access instructions
     /// Returns whether or not a memory access instruction is volatile.
     pub fn get_volatile(&self) -> bool {
@@ -330,6 +516,192 @@ impl InstructionValue {
             LLVMSetVolatile(self.as_value_ref(), volatile as i32)
         }
     }
+
+    /// Gets the number of operands of this instruction. For example, an `add` has two
+    /// operands while a conditional `br` has three: its condition and both target blocks.
+    pub fn get_num_operands(&self) -> u32 {
+        unsafe {
+            LLVMGetNumOperands(self.as_value_ref()) as u32
+        }
+    }
+
+    /// Gets the operand at `index`. Instructions which branch, such as `br` and `switch`,
+    /// have `BasicBlock` operands, while all other operands are `BasicValueEnum`s.
+    ///
+    /// Returns `None` if `index` is out of bounds, or if the operand is neither a basic
+    /// value nor a `BasicBlock` (such as a metadata argument to an intrinsic call).
+    /// Note that a `phi`'s incoming blocks are not operands; see `PhiValue::get_incoming`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let void_type = context.void_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    /// let function = module.add_function("my_fn", &fn_type, None);
+    /// let entry = context.append_basic_block(&function, "entry");
+    /// let exit = context.append_basic_block(&function, "exit");
+    ///
+    /// builder.position_at_end(&entry);
+    ///
+    /// let branch = builder.build_unconditional_branch(&exit);
+    ///
+    /// assert_eq!(branch.get_num_operands(), 1);
+    /// assert_eq!(branch.get_operand(0).unwrap().right(), Some(exit));
+    /// assert!(branch.get_operand(1).is_none());
+    /// ```
+    pub fn get_operand(&self, index: u32) -> Option<Either<BasicValueEnum, BasicBlock>> {
+        if index >= self.get_num_operands() {
+            return None;
+        }
+
+        let operand = unsafe {
+            LLVMGetOperand(self.as_value_ref(), index)
+        };
+
+        if operand.is_null() {
+            return None;
+        }
+
+        let is_basic_block = unsafe {
+            LLVMValueIsBasicBlock(operand) == 1
+        };
+
+        if is_basic_block {
+            let basic_block = unsafe {
+                LLVMValueAsBasicBlock(operand)
+            };
+
+            return BasicBlock::new(basic_block).map(Either::Right);
+        }
+
+        let type_kind = unsafe {
+            LLVMGetTypeKind(LLVMTypeOf(operand))
+        };
+
+        match type_kind {
+            LLVMTypeKind::LLVMFloatTypeKind |
+            LLVMTypeKind::LLVMFP128TypeKind |
+            LLVMTypeKind::LLVMDoubleTypeKind |
+            LLVMTypeKind::LLVMHalfTypeKind |
+            LLVMTypeKind::LLVMX86_FP80TypeKind |
+            LLVMTypeKind::LLVMPPC_FP128TypeKind |
+            LLVMTypeKind::LLVMIntegerTypeKind |
+            LLVMTypeKind::LLVMStructTypeKind |
+            LLVMTypeKind::LLVMPointerTypeKind |
+            LLVMTypeKind::LLVMArrayTypeKind |
+            LLVMTypeKind::LLVMVectorTypeKind => Some(Either::Left(BasicValueEnum::new(operand))),
+            _ => None,
+        }
+    }
+
+    /// Sets the operand at `index` to `value`. Returns `false`, leaving the instruction
+    /// unchanged, if `index` is out of bounds.
+    ///
+    /// It is up to the caller to ensure `value` has the type the instruction expects,
+    /// otherwise the `Module` will fail to verify.
+    pub fn set_operand(&self, index: u32, value: &BasicValue) -> bool {
+        if index >= self.get_num_operands() {
+            return false;
+        }
+
+        unsafe {
+            LLVMSetOperand(self.as_value_ref(), index, value.as_value_ref())
+        }
+
+        true
+    }
+
+    /// Counts the `BasicBlock`s this instruction may transfer control to, which is zero
+    /// for any instruction other than a terminator such as `br`, `switch` or `invoke`.
+    pub fn count_successors(&self) -> u32 {
+        self.get_successor_operand_indices().len() as u32
+    }
+
+    /// Gets the successor at `index`, following LLVM's order: for a conditional `br` the
+    /// block taken when the condition is true comes first, for a `switch` the default block
+    /// comes first, and for an `invoke` the normal destination comes before the unwind one.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let void_type = context.void_type();
+    /// let bool_type = context.bool_type();
+    /// let fn_type = void_type.fn_type(&[&bool_type], false);
+    /// let function = module.add_function("my_fn", &fn_type, None);
+    /// let entry = context.append_basic_block(&function, "entry");
+    /// let then_block = context.append_basic_block(&function, "then");
+    /// let else_block = context.append_basic_block(&function, "else");
+    /// let condition = function.get_first_param().unwrap().into_int_value();
+    ///
+    /// builder.position_at_end(&entry);
+    ///
+    /// let branch = builder.build_conditional_branch(&condition, &then_block, &else_block);
+    ///
+    /// assert_eq!(branch.count_successors(), 2);
+    /// assert_eq!(branch.get_successor(0), Some(then_block));
+    /// assert_eq!(branch.get_successor(1), Some(else_block));
+    /// assert!(branch.get_successor(2).is_none());
+    /// ```
+    pub fn get_successor(&self, index: u32) -> Option<BasicBlock> {
+        let operand_index = match self.get_successor_operand_indices().get(index as usize) {
+            Some(&operand_index) => operand_index,
+            None => return None,
+        };
+
+        let basic_block = unsafe {
+            LLVMValueAsBasicBlock(LLVMGetOperand(self.as_value_ref(), operand_index))
+        };
+
+        BasicBlock::new(basic_block)
+    }
+
+    /// Replaces the successor at `index` with `basic_block`. Returns `false`, leaving the
+    /// instruction unchanged, if `index` is out of bounds.
+    ///
+    /// Note that phi nodes in either block are not updated.
+    pub fn set_successor(&self, index: u32, basic_block: &BasicBlock) -> bool {
+        let operand_index = match self.get_successor_operand_indices().get(index as usize) {
+            Some(&operand_index) => operand_index,
+            None => return false,
+        };
+
+        unsafe {
+            LLVMSetOperand(self.as_value_ref(), operand_index, LLVMBasicBlockAsValue(basic_block.basic_block))
+        }
+
+        true
+    }
+
+    // REVIEW: LLVMGetSuccessor and LLVMSetSuccessor aren't available in every supported
+    // version, so successors are found among the operands instead. Every terminator stores
+    // them in successor order, except for a conditional br which stores them in reverse
+    fn get_successor_operand_indices(&self) -> Vec<u32> {
+        let is_terminator = unsafe {
+            !LLVMIsATerminatorInst(self.as_value_ref()).is_null()
+        };
+
+        if !is_terminator {
+            return Vec::new();
+        }
+
+        let mut indices: Vec<u32> = (0..self.get_num_operands())
+            .filter(|&index| unsafe { LLVMValueIsBasicBlock(LLVMGetOperand(self.as_value_ref(), index)) == 1 })
+            .collect();
+
+        if self.get_opcode() == InstructionOpcode::Br {
+            indices.reverse();
+        }
+
+        indices
+    }
 }
 
 impl Clone for InstructionValue {
@@ -349,3 +721,36 @@ impl AsValueRef for InstructionValue {
         self.instruction_value.value
     }
 }
+
+/// An instruction which has been unlinked from its basic block by `InstructionValue::detach`.
+/// It must be consumed by either `Builder::insert_detached_instruction` or `erase`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct DetachedInstruction {
+    pub(crate) instruction: InstructionValue,
+}
+
+impl DetachedInstruction {
+    pub fn as_instruction(&self) -> &InstructionValue {
+        &self.instruction
+    }
+
+    /// Deletes this instruction. The C API can only delete instructions through their basic
+    /// block, so it is first inserted at `builder`'s position, which is left unchanged.
+    ///
+    /// Returns this `DetachedInstruction` back if it still has uses.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `builder` is not positioned in a basic block.
+    pub fn erase(self, builder: &Builder) -> Result<(), DetachedInstruction> {
+        if self.instruction.get_first_use().is_some() {
+            return Err(self);
+        }
+
+        assert!(builder.get_insert_block().is_some(), "Builder must be positioned in a basic block");
+
+        builder.insert_detached_instruction(self).erase().expect("Instruction should have no uses");
+
+        Ok(())
+    }
+}
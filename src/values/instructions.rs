@@ -0,0 +1,255 @@
+use llvm_sys::core::{LLVMGetOperand, LLVMGetElementType, LLVMTypeOf, LLVMIsAFunction, LLVMGetICmpPredicate, LLVMGetInstructionCallConv, LLVMValueAsBasicBlock};
+use llvm_sys::prelude::LLVMValueRef;
+
+use IntPredicate;
+#[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+use FloatPredicate;
+use basic_block::BasicBlock;
+use types::BasicTypeEnum;
+use values::traits::AsValueRef;
+use values::{BasicValueEnum, FunctionValue, InstructionValue, IntValue, PointerValue};
+
+// Each view wraps an InstructionValue which is known to have a specific opcode
+macro_rules! instruction_view {
+    ($(#[$attr:meta])* $name:ident) => (
+        $(#[$attr])*
+        #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+        pub struct $name {
+            instruction: InstructionValue,
+        }
+
+        impl $name {
+            pub(crate) fn new(instruction: InstructionValue) -> Self {
+                $name {
+                    instruction,
+                }
+            }
+
+            /// Gets the underlying `InstructionValue`.
+            pub fn as_instruction(&self) -> InstructionValue {
+                self.instruction
+            }
+        }
+    );
+}
+
+fn get_operand(instruction: &InstructionValue, index: u32) -> LLVMValueRef {
+    unsafe {
+        LLVMGetOperand(instruction.as_value_ref(), index)
+    }
+}
+
+instruction_view! {
+    /// An `alloca` instruction. See `InstructionValue::as_alloca`.
+    AllocaInst
+}
+
+impl AllocaInst {
+    /// Gets the type of the memory allocated on the stack.
+    pub fn get_allocated_type(&self) -> BasicTypeEnum {
+        let type_ = unsafe {
+            LLVMGetElementType(LLVMTypeOf(self.instruction.as_value_ref()))
+        };
+
+        BasicTypeEnum::new(type_)
+    }
+
+    /// Gets the number of elements allocated, which is one unless built with `Builder::build_array_alloca`.
+    pub fn get_array_size(&self) -> IntValue {
+        IntValue::new(get_operand(&self.instruction, 0))
+    }
+}
+
+instruction_view! {
+    /// A `load` instruction. See `InstructionValue::as_load`.
+    LoadInst
+}
+
+impl LoadInst {
+    /// Gets the pointer being loaded from.
+    pub fn get_pointer_operand(&self) -> PointerValue {
+        PointerValue::new(get_operand(&self.instruction, 0))
+    }
+
+    /// Determines whether or not this load is volatile, in which case it may not be removed
+    /// or reordered with other volatile operations.
+    pub fn is_volatile(&self) -> bool {
+        self.instruction.get_volatile()
+    }
+}
+
+instruction_view! {
+    /// A `store` instruction. See `InstructionValue::as_store`.
+    StoreInst
+}
+
+impl StoreInst {
+    /// Gets the value being stored.
+    pub fn get_value_operand(&self) -> BasicValueEnum {
+        BasicValueEnum::new(get_operand(&self.instruction, 0))
+    }
+
+    /// Gets the pointer being stored to.
+    pub fn get_pointer_operand(&self) -> PointerValue {
+        PointerValue::new(get_operand(&self.instruction, 1))
+    }
+
+    /// Determines whether or not this store is volatile. See `LoadInst::is_volatile`.
+    pub fn is_volatile(&self) -> bool {
+        self.instruction.get_volatile()
+    }
+}
+
+instruction_view! {
+    /// A `call` instruction. See `InstructionValue::as_call`.
+    CallInst
+}
+
+impl CallInst {
+    /// Counts the arguments passed to the callee.
+    pub fn count_arguments(&self) -> u32 {
+        // The callee is always the last operand
+        self.instruction.get_num_operands().saturating_sub(1)
+    }
+
+    /// Gets the argument at `index`. Returns `None` if `index` is out of bounds or if the
+    /// argument isn't a basic value, such as a metadata argument to an intrinsic.
+    pub fn get_argument(&self, index: u32) -> Option<BasicValueEnum> {
+        if index >= self.count_arguments() {
+            return None;
+        }
+
+        self.instruction.get_operand(index).and_then(|operand| operand.left())
+    }
+
+    /// Gets the value being called, which is a function pointer.
+    pub fn get_called_value(&self) -> PointerValue {
+        PointerValue::new(get_operand(&self.instruction, self.count_arguments()))
+    }
+
+    /// Gets the function being called, if it is called directly rather than through a pointer.
+    pub fn get_called_function(&self) -> Option<FunctionValue> {
+        let called_value = get_operand(&self.instruction, self.count_arguments());
+
+        let is_function = unsafe {
+            !LLVMIsAFunction(called_value).is_null()
+        };
+
+        if !is_function {
+            return None;
+        }
+
+        FunctionValue::new(called_value)
+    }
+
+    /// Determines whether or not this call is marked `tail`, which hints that the caller's
+    /// stack frame may be reused by the callee.
+    pub fn is_tail_call(&self) -> bool {
+        self.instruction.is_tail_call()
+    }
+
+    /// Gets the calling convention used by this call, as an LLVM `CallingConv` id. Zero is
+    /// the C calling convention, and should match the callee's `FunctionValue::get_call_conventions`.
+    pub fn get_call_conventions(&self) -> u32 {
+        unsafe {
+            LLVMGetInstructionCallConv(self.instruction.as_value_ref())
+        }
+    }
+}
+
+instruction_view! {
+    /// An `icmp` instruction. See `InstructionValue::as_icmp`.
+    ICmpInst
+}
+
+impl ICmpInst {
+    /// Gets the `IntPredicate` used to compare both operands.
+    pub fn get_predicate(&self) -> IntPredicate {
+        let predicate = unsafe {
+            LLVMGetICmpPredicate(self.instruction.as_value_ref())
+        };
+
+        IntPredicate::new(predicate)
+    }
+
+    /// Gets the left hand side of the comparison, which is either an int, a pointer or a vector of either.
+    pub fn get_lhs(&self) -> BasicValueEnum {
+        BasicValueEnum::new(get_operand(&self.instruction, 0))
+    }
+
+    /// Gets the right hand side of the comparison, which is either an int, a pointer or a vector of either.
+    pub fn get_rhs(&self) -> BasicValueEnum {
+        BasicValueEnum::new(get_operand(&self.instruction, 1))
+    }
+}
+
+instruction_view! {
+    /// An `fcmp` instruction. See `InstructionValue::as_fcmp`.
+    FCmpInst
+}
+
+impl FCmpInst {
+    /// Gets the `FloatPredicate` used to compare both operands.
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+    pub fn get_predicate(&self) -> FloatPredicate {
+        use llvm_sys::core::LLVMGetFCmpPredicate;
+
+        let predicate = unsafe {
+            LLVMGetFCmpPredicate(self.instruction.as_value_ref())
+        };
+
+        FloatPredicate::new(predicate)
+    }
+
+    /// Gets the left hand side of the comparison, which is either a float or a vector of floats.
+    pub fn get_lhs(&self) -> BasicValueEnum {
+        BasicValueEnum::new(get_operand(&self.instruction, 0))
+    }
+
+    /// Gets the right hand side of the comparison, which is either a float or a vector of floats.
+    pub fn get_rhs(&self) -> BasicValueEnum {
+        BasicValueEnum::new(get_operand(&self.instruction, 1))
+    }
+}
+
+instruction_view! {
+    /// A `br` instruction. See `InstructionValue::as_branch`.
+    BranchInst
+}
+
+impl BranchInst {
+    /// Determines whether this branch depends on a condition, as built by `Builder::build_conditional_branch`.
+    pub fn is_conditional(&self) -> bool {
+        self.instruction.get_num_operands() == 3
+    }
+
+    /// Gets the condition of a conditional branch.
+    pub fn get_condition(&self) -> Option<IntValue> {
+        if !self.is_conditional() {
+            return None;
+        }
+
+        Some(IntValue::new(get_operand(&self.instruction, 0)))
+    }
+
+    /// Gets the blocks this branch may jump to. For a conditional branch, the block taken when
+    /// the condition is true comes first.
+    pub fn get_successors(&self) -> Vec<BasicBlock> {
+        // LLVM stores a conditional branch's operands as condition, false block, true block
+        let indices = if self.is_conditional() {
+            vec![2, 1]
+        } else {
+            vec![0]
+        };
+
+        indices.into_iter()
+            .map(|index| {
+                let basic_block = unsafe {
+                    LLVMValueAsBasicBlock(get_operand(&self.instruction, index))
+                };
+
+                BasicBlock::new(basic_block).expect("Branch targets should never be null")
+            })
+            .collect()
+    }
+}
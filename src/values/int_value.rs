@@ -1,4 +1,4 @@
-use llvm_sys::core::{LLVMConstNot, LLVMConstNeg, LLVMConstNSWNeg, LLVMConstNUWNeg, LLVMConstAdd, LLVMConstNSWAdd, LLVMConstNUWAdd, LLVMConstSub, LLVMConstNSWSub, LLVMConstNUWSub, LLVMConstMul, LLVMConstNSWMul, LLVMConstNUWMul, LLVMConstUDiv, LLVMConstSDiv, LLVMConstSRem, LLVMConstURem, LLVMConstIntCast, LLVMConstXor, LLVMConstOr, LLVMConstAnd, LLVMConstExactSDiv, LLVMConstShl, LLVMConstLShr, LLVMConstAShr, LLVMConstUIToFP, LLVMConstSIToFP, LLVMConstIntToPtr, LLVMConstTrunc, LLVMConstSExt, LLVMConstZExt, LLVMConstTruncOrBitCast, LLVMConstSExtOrBitCast, LLVMConstZExtOrBitCast, LLVMConstBitCast, LLVMConstICmp};
+use llvm_sys::core::{LLVMConstNot, LLVMConstNeg, LLVMConstNSWNeg, LLVMConstNUWNeg, LLVMConstAdd, LLVMConstNSWAdd, LLVMConstNUWAdd, LLVMConstSub, LLVMConstNSWSub, LLVMConstNUWSub, LLVMConstMul, LLVMConstNSWMul, LLVMConstNUWMul, LLVMConstUDiv, LLVMConstSDiv, LLVMConstSRem, LLVMConstURem, LLVMConstIntCast, LLVMConstXor, LLVMConstOr, LLVMConstAnd, LLVMConstExactSDiv, LLVMConstShl, LLVMConstLShr, LLVMConstAShr, LLVMConstUIToFP, LLVMConstSIToFP, LLVMConstIntToPtr, LLVMConstTrunc, LLVMConstSExt, LLVMConstZExt, LLVMConstTruncOrBitCast, LLVMConstSExtOrBitCast, LLVMConstZExtOrBitCast, LLVMConstBitCast, LLVMConstICmp, LLVMConstInt, LLVMConstIntGetZExtValue, LLVMConstIntGetSExtValue, LLVMIsAConstantInt};
 use llvm_sys::prelude::LLVMValueRef;
 
 use std::ffi::CStr;
@@ -367,6 +367,87 @@ impl IntValue {
         IntValue::new(value)
     }
 
+    fn is_const_int(&self) -> bool {
+        unsafe {
+            !LLVMIsAConstantInt(self.as_value_ref()).is_null()
+        }
+    }
+
+    /// Gets the value of a constant integer of at most 64 bits, zero extended to a `u64`.
+    /// Returns `None` if this isn't a constant integer, or if it is wider than 64 bits.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let i8_type = context.i8_type();
+    /// let i8_val = i8_type.const_int(255, false);
+    ///
+    /// assert_eq!(i8_val.get_zero_extended_constant(), Some(255));
+    /// assert_eq!(i8_val.get_sign_extended_constant(), Some(-1));
+    /// ```
+    pub fn get_zero_extended_constant(&self) -> Option<u64> {
+        if !self.is_const_int() || self.get_type().get_bit_width() > 64 {
+            return None;
+        }
+
+        unsafe {
+            Some(LLVMConstIntGetZExtValue(self.as_value_ref()))
+        }
+    }
+
+    /// Gets the value of a constant integer of at most 64 bits, sign extended to an `i64`.
+    /// Returns `None` if this isn't a constant integer, or if it is wider than 64 bits.
+    pub fn get_sign_extended_constant(&self) -> Option<i64> {
+        if !self.is_const_int() || self.get_type().get_bit_width() > 64 {
+            return None;
+        }
+
+        unsafe {
+            Some(LLVMConstIntGetSExtValue(self.as_value_ref()))
+        }
+    }
+
+    /// Gets the value of a constant integer of any width as 64 bit words, least significant
+    /// word first. This is the inverse of `IntType::const_int_arbitrary_precision`.
+    /// Returns `None` if this isn't a constant integer.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let i128_type = context.i128_type();
+    /// let i128_val = i128_type.const_int_arbitrary_precision(&[1, 2]);
+    ///
+    /// assert_eq!(i128_val.get_arbitrary_precision_constant(), Some(vec![1, 2]));
+    /// ```
+    pub fn get_arbitrary_precision_constant(&self) -> Option<Vec<u64>> {
+        if !self.is_const_int() {
+            return None;
+        }
+
+        let int_type = self.get_type();
+        let bit_width = int_type.get_bit_width();
+
+        if bit_width <= 64 {
+            return self.get_zero_extended_constant().map(|word| vec![word]);
+        }
+
+        // There's no C API to read the words directly, so each one is shifted down
+        // and truncated to 64 bits, which LLVM folds into a new constant integer
+        let i64_type = int_type.get_context().i64_type();
+        let words = (0..(bit_width + 63) / 64).map(|index| unsafe {
+            let shift = LLVMConstInt(int_type.as_type_ref(), index as u64 * 64, 0);
+            let word = LLVMConstTrunc(LLVMConstLShr(self.as_value_ref(), shift), i64_type.as_type_ref());
+
+            LLVMConstIntGetZExtValue(word)
+        }).collect();
+
+        Some(words)
+    }
+
     pub fn replace_all_uses_with(&self, other: &IntValue) {
         self.int_value.replace_all_uses_with(other.as_value_ref())
     }
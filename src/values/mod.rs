@@ -1,10 +1,12 @@
 mod array_value;
+mod basic_value_use;
 mod enums;
 mod float_value;
 mod fn_value;
 mod generic_value;
 mod global_value;
 mod instruction_value;
+mod instructions;
 mod int_value;
 mod metadata_value;
 mod phi_value;
@@ -14,12 +16,14 @@ mod traits;
 mod vec_value;
 
 pub use values::array_value::ArrayValue;
+pub use values::basic_value_use::{BasicValueUse, BasicValueUseIter};
 pub use values::enums::{AnyValueEnum, AggregateValueEnum, BasicValueEnum, BasicMetadataValueEnum};
 pub use values::float_value::FloatValue;
-pub use values::fn_value::FunctionValue;
+pub use values::fn_value::{BasicBlockIter, FunctionInstructionIter, FunctionValue};
 pub use values::generic_value::GenericValue;
 pub use values::global_value::GlobalValue;
-pub use values::instruction_value::{InstructionValue, InstructionOpcode};
+pub use values::instruction_value::{DetachedInstruction, InstructionValue, InstructionOpcode};
+pub use values::instructions::{AllocaInst, BranchInst, CallInst, FCmpInst, ICmpInst, LoadInst, StoreInst};
 pub use values::int_value::IntValue;
 pub use values::metadata_value::{MetadataValue, FIRST_CUSTOM_METADATA_KIND_ID};
 pub use values::phi_value::PhiValue;
@@ -1,8 +1,9 @@
+use llvm_sys::core::LLVMGetFirstUse;
 use llvm_sys::prelude::LLVMValueRef;
 
 use std::fmt::Debug;
 
-use values::{ArrayValue, AggregateValueEnum, GlobalValue, StructValue, BasicValueEnum, AnyValueEnum, IntValue, FloatValue, PointerValue, PhiValue, VectorValue, FunctionValue, InstructionValue};
+use values::{ArrayValue, AggregateValueEnum, BasicValueUse, BasicValueUseIter, GlobalValue, StructValue, BasicValueEnum, AnyValueEnum, IntValue, FloatValue, PointerValue, PhiValue, VectorValue, FunctionValue, InstructionValue};
 
 // This is an ugly privacy hack so that Type can stay private to this module
 // and so that super traits using this trait will be not be implementable
@@ -44,6 +45,20 @@ pub trait AnyValue: AsValueRef + Debug {
     fn as_any_value_enum(&self) -> AnyValueEnum {
         AnyValueEnum::new(self.as_value_ref())
     }
+
+    /// Gets the first use of this value, if any. See `BasicValueUse::get_next_use`.
+    fn get_first_use(&self) -> Option<BasicValueUse> {
+        let use_value = unsafe {
+            LLVMGetFirstUse(self.as_value_ref())
+        };
+
+        BasicValueUse::new(use_value)
+    }
+
+    /// Gets an iterator over the uses of this value. See `BasicValueUseIter`.
+    fn uses(&self) -> BasicValueUseIter {
+        BasicValueUseIter::new(self.get_first_use())
+    }
 }
 
 trait_value_set! {AggregateValue: ArrayValue, AggregateValueEnum, StructValue}
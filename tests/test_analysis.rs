@@ -0,0 +1,248 @@
+extern crate inkwell;
+
+use self::inkwell::analysis::{DominatorTree, LoopInfo, PostDominatorTree};
+use self::inkwell::basic_block::BasicBlock;
+use self::inkwell::context::Context;
+
+fn names(basic_blocks: Vec<BasicBlock>) -> Vec<String> {
+    basic_blocks.iter().map(|basic_block| basic_block.get_name().to_string_lossy().into_owned()).collect()
+}
+
+#[test]
+fn test_dominator_tree() {
+    let context = Context::create();
+    let module = context.create_module("test");
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let bool_type = context.bool_type();
+    let fn_type = void_type.fn_type(&[&bool_type], false);
+    let function = module.add_function("diamond", &fn_type, None);
+    let condition = function.get_first_param().unwrap().into_int_value();
+
+    // entry -> (left | right) -> merge -> exit, plus a block nothing branches to
+    let entry = function.append_basic_block("entry");
+    let left = function.append_basic_block("left");
+    let right = function.append_basic_block("right");
+    let merge = function.append_basic_block("merge");
+    let exit = function.append_basic_block("exit");
+    let dead = function.append_basic_block("dead");
+
+    builder.position_at_end(&entry);
+    builder.build_conditional_branch(&condition, &left, &right);
+    builder.position_at_end(&left);
+    builder.build_unconditional_branch(&merge);
+    builder.position_at_end(&right);
+    builder.build_unconditional_branch(&merge);
+    builder.position_at_end(&merge);
+    builder.build_unconditional_branch(&exit);
+    builder.position_at_end(&exit);
+    builder.build_return(None);
+    builder.position_at_end(&dead);
+    builder.build_unconditional_branch(&merge);
+
+    let dominator_tree = DominatorTree::new(&function);
+
+    assert_eq!(dominator_tree.get_root().as_ref(), Some(&entry));
+    assert!(dominator_tree.dominates(&entry, &entry));
+    assert!(!dominator_tree.strictly_dominates(&entry, &entry));
+    assert!(dominator_tree.strictly_dominates(&entry, &exit));
+    assert!(dominator_tree.dominates(&merge, &exit));
+    assert!(!dominator_tree.dominates(&left, &merge));
+    assert!(!dominator_tree.dominates(&exit, &merge));
+
+    assert!(dominator_tree.get_immediate_dominator(&entry).is_none());
+    assert_eq!(dominator_tree.get_immediate_dominator(&left).as_ref(), Some(&entry));
+    assert_eq!(dominator_tree.get_immediate_dominator(&merge).as_ref(), Some(&entry));
+    assert_eq!(dominator_tree.get_immediate_dominator(&exit).as_ref(), Some(&merge));
+
+    let mut children = names(dominator_tree.get_children(&entry));
+
+    children.sort();
+
+    assert_eq!(children, vec!["left", "merge", "right"]);
+    assert!(dominator_tree.get_children(&exit).is_empty());
+
+    // Unreachable blocks take no part in dominance, even as a predecessor of merge
+    assert!(dominator_tree.is_reachable(&merge));
+    assert!(!dominator_tree.is_reachable(&dead));
+    assert!(!dominator_tree.dominates(&dead, &dead));
+    assert!(!dominator_tree.dominates(&entry, &dead));
+    assert!(dominator_tree.get_immediate_dominator(&dead).is_none());
+
+    let order = names(dominator_tree.get_reverse_postorder());
+
+    assert_eq!(order.len(), 5);
+    assert_eq!(order[0], "entry");
+    assert_eq!(order[3], "merge");
+    assert_eq!(order[4], "exit");
+
+    assert_eq!(names(dominator_tree.get_dominance_frontier(&left)), vec!["merge"]);
+    assert_eq!(names(dominator_tree.get_dominance_frontier(&right)), vec!["merge"]);
+    assert!(dominator_tree.get_dominance_frontier(&entry).is_empty());
+    assert!(dominator_tree.get_dominance_frontier(&merge).is_empty());
+    assert_eq!(names(dominator_tree.get_iterated_dominance_frontier(&[&left, &exit])), vec!["merge"]);
+
+    // Blocks from elsewhere are simply unknown
+    let other_function = module.add_function("other", &fn_type, None);
+    let other_block = other_function.append_basic_block("entry");
+
+    assert!(!dominator_tree.is_reachable(&other_block));
+    assert!(!dominator_tree.dominates(&entry, &other_block));
+    assert!(dominator_tree.get_dominance_frontier(&other_block).is_empty());
+
+    let declaration = module.add_function("declaration", &fn_type, None);
+    let declaration_tree = DominatorTree::new(&declaration);
+
+    assert!(declaration_tree.get_root().is_none());
+    assert!(declaration_tree.get_reverse_postorder().is_empty());
+    assert!(LoopInfo::new(&declaration_tree).get_loops().is_empty());
+}
+
+#[test]
+fn test_post_dominator_tree() {
+    let context = Context::create();
+    let module = context.create_module("test");
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let bool_type = context.bool_type();
+    let fn_type = void_type.fn_type(&[&bool_type], false);
+    let function = module.add_function("exits", &fn_type, None);
+    let condition = function.get_first_param().unwrap().into_int_value();
+
+    // entry -> (body | early) ; body -> (late | spin) ; spin never exits
+    let entry = function.append_basic_block("entry");
+    let body = function.append_basic_block("body");
+    let early = function.append_basic_block("early");
+    let late = function.append_basic_block("late");
+    let spin = function.append_basic_block("spin");
+
+    builder.position_at_end(&entry);
+    builder.build_conditional_branch(&condition, &body, &early);
+    builder.position_at_end(&body);
+    builder.build_conditional_branch(&condition, &late, &spin);
+    builder.position_at_end(&early);
+    builder.build_return(None);
+    builder.position_at_end(&late);
+    builder.build_unreachable();
+    builder.position_at_end(&spin);
+    builder.build_unconditional_branch(&spin);
+
+    let post_dominator_tree = PostDominatorTree::new(&function);
+
+    assert_eq!(names(post_dominator_tree.get_roots()), vec!["early", "late"]);
+    assert!(post_dominator_tree.get_immediate_post_dominator(&early).is_none());
+    assert!(post_dominator_tree.get_immediate_post_dominator(&entry).is_none());
+    assert_eq!(post_dominator_tree.get_immediate_post_dominator(&body).as_ref(), Some(&late));
+    assert!(post_dominator_tree.post_dominates(&late, &body));
+    assert!(post_dominator_tree.post_dominates(&late, &late));
+    assert!(!post_dominator_tree.strictly_post_dominates(&late, &late));
+    assert!(!post_dominator_tree.post_dominates(&late, &entry));
+    assert!(!post_dominator_tree.post_dominates(&early, &entry));
+    assert_eq!(names(post_dominator_tree.get_children(&late)), vec!["body"]);
+
+    // Blocks which can't reach an exit aren't part of the tree
+    assert!(post_dominator_tree.is_reachable(&entry));
+    assert!(!post_dominator_tree.is_reachable(&spin));
+    assert!(!post_dominator_tree.post_dominates(&spin, &spin));
+    assert!(post_dominator_tree.get_immediate_post_dominator(&spin).is_none());
+}
+
+#[test]
+fn test_loop_info() {
+    let context = Context::create();
+    let module = context.create_module("test");
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let i32_type = context.i32_type();
+    let bool_type = context.bool_type();
+    let fn_type = void_type.fn_type(&[&bool_type, &i32_type], false);
+    let function = module.add_function("loops", &fn_type, None);
+    let condition = function.get_first_param().unwrap().into_int_value();
+    let param = function.get_nth_param(1).unwrap().into_int_value();
+
+    // entry -> outer -> inner <-> inner ; inner -> outer_latch -> (outer | exit)
+    // outer -> (inner | break) ; break -> exit ; exit -> second <-> second ; second -> done
+    let entry = function.append_basic_block("entry");
+    let outer = function.append_basic_block("outer");
+    let inner = function.append_basic_block("inner");
+    let outer_latch = function.append_basic_block("outer_latch");
+    let break_block = function.append_basic_block("break");
+    let exit = function.append_basic_block("exit");
+    let second = function.append_basic_block("second");
+    let done = function.append_basic_block("done");
+
+    builder.position_at_end(&entry);
+
+    let outside = builder.build_int_add(&param, &param, "outside");
+
+    builder.build_unconditional_branch(&outer);
+    builder.position_at_end(&outer);
+    builder.build_conditional_branch(&condition, &inner, &break_block);
+    builder.position_at_end(&inner);
+
+    let inside = builder.build_int_mul(&outside, &param, "inside");
+
+    builder.build_conditional_branch(&condition, &inner, &outer_latch);
+    builder.position_at_end(&outer_latch);
+    builder.build_conditional_branch(&condition, &outer, &exit);
+    builder.position_at_end(&break_block);
+    builder.build_unconditional_branch(&exit);
+    builder.position_at_end(&exit);
+    builder.build_unconditional_branch(&second);
+    builder.position_at_end(&second);
+    builder.build_conditional_branch(&condition, &second, &done);
+    builder.position_at_end(&done);
+    builder.build_return(None);
+
+    assert!(function.verify(false));
+
+    let dominator_tree = DominatorTree::new(&function);
+    let loop_info = LoopInfo::new(&dominator_tree);
+    let loops = loop_info.get_loops();
+
+    assert_eq!(loops.len(), 3);
+    assert_eq!(loop_info.get_top_level_loops().len(), 2);
+
+    let outer_loop = loop_info.get_loop_for(&outer).unwrap();
+    let inner_loop = loop_info.get_loop_for(&inner).unwrap();
+    let second_loop = loop_info.get_loop_for(&second).unwrap();
+
+    assert_eq!(outer_loop.get_header(), outer);
+    assert_eq!(names(outer_loop.get_blocks()), vec!["outer", "inner", "outer_latch"]);
+    assert_eq!(names(outer_loop.get_latches()), vec!["outer_latch"]);
+    assert_eq!(names(outer_loop.get_exiting_blocks()), vec!["outer", "outer_latch"]);
+    assert_eq!(names(outer_loop.get_exit_blocks()).len(), 2);
+    assert_eq!(outer_loop.get_depth(), 1);
+    assert!(loop_info.get_parent_loop(outer_loop).is_none());
+    assert_eq!(loop_info.get_sub_loops(outer_loop), vec![inner_loop]);
+
+    assert_eq!(inner_loop.get_header(), inner);
+    assert_eq!(names(inner_loop.get_blocks()), vec!["inner"]);
+    assert_eq!(names(inner_loop.get_latches()), vec!["inner"]);
+    assert_eq!(names(inner_loop.get_exit_blocks()), vec!["outer_latch"]);
+    assert_eq!(inner_loop.get_depth(), 2);
+    assert_eq!(loop_info.get_parent_loop(inner_loop), Some(outer_loop));
+    assert!(loop_info.get_sub_loops(inner_loop).is_empty());
+    assert!(outer_loop.contains(&inner));
+    assert!(!inner_loop.contains(&outer));
+
+    assert_eq!(second_loop.get_depth(), 1);
+    assert_eq!(names(second_loop.get_exit_blocks()), vec!["done"]);
+
+    assert_eq!(loop_info.get_loop_for(&outer_latch), Some(outer_loop));
+    assert!(loop_info.get_loop_for(&entry).is_none());
+    assert!(loop_info.get_loop_for(&exit).is_none());
+    assert_eq!(loop_info.get_loop_depth(&entry), 0);
+    assert_eq!(loop_info.get_loop_depth(&outer_latch), 1);
+    assert_eq!(loop_info.get_loop_depth(&inner), 2);
+    assert!(loop_info.is_loop_header(&inner));
+    assert!(!loop_info.is_loop_header(&outer_latch));
+
+    // Loop invariance
+    assert!(inner_loop.is_invariant(&outside));
+    assert!(inner_loop.is_invariant(&param));
+    assert!(inner_loop.is_invariant(&i32_type.const_int(1, false)));
+    assert!(!inner_loop.is_invariant(&inside));
+    assert!(!outer_loop.is_invariant(&inside));
+    assert!(second_loop.is_invariant(&inside));
+}
@@ -0,0 +1,131 @@
+// Attributes are only available from LLVM 3.9 onwards
+#![cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+
+extern crate inkwell;
+
+use self::inkwell::AddressSpace;
+use self::inkwell::attributes::{Attribute, AttributeLoc};
+use self::inkwell::context::Context;
+
+use std::ffi::CString;
+
+#[test]
+fn test_enum_attribute_kinds() {
+    assert_eq!(Attribute::get_named_enum_kind_id("foobar"), 0);
+
+    for name in &["noinline", "alwaysinline", "nounwind", "readonly", "noalias", "nonnull", "sret", "byval", "zeroext"] {
+        let kind_id = Attribute::get_named_enum_kind_id(name);
+
+        assert_ne!(kind_id, 0);
+        assert!(kind_id <= Attribute::get_last_enum_kind_id());
+    }
+}
+
+#[test]
+fn test_attributes() {
+    let context = Context::create();
+    let enum_attribute = context.create_enum_attribute(Attribute::get_named_enum_kind_id("align"), 16);
+    let string_attribute = context.create_string_attribute("target-cpu", "x86-64");
+
+    assert!(enum_attribute.is_enum());
+    assert!(!enum_attribute.is_string());
+    assert_eq!(enum_attribute.get_enum_kind_id(), Attribute::get_named_enum_kind_id("align"));
+    assert_eq!(enum_attribute.get_enum_value(), 16);
+
+    assert!(string_attribute.is_string());
+    assert!(!string_attribute.is_enum());
+    assert_eq!(*string_attribute.get_string_kind_id(), *CString::new("target-cpu").unwrap());
+    assert_eq!(*string_attribute.get_string_value(), *CString::new("x86-64").unwrap());
+}
+
+#[test]
+fn test_function_attributes() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    let void_type = context.void_type();
+    let i32_ptr_type = i32_type.ptr_type(AddressSpace::Generic);
+    let fn_type = void_type.fn_type(&[&i32_ptr_type, &i32_ptr_type, &i32_type], false);
+    let fn_value = module.add_function("my_fn", &fn_type, None);
+
+    let sret_kind_id = Attribute::get_named_enum_kind_id("sret");
+    let byval_kind_id = Attribute::get_named_enum_kind_id("byval");
+    let zeroext_kind_id = Attribute::get_named_enum_kind_id("zeroext");
+    let nounwind_kind_id = Attribute::get_named_enum_kind_id("nounwind");
+    let noinline_kind_id = Attribute::get_named_enum_kind_id("noinline");
+
+    let sret = context.create_enum_attribute(sret_kind_id, 0);
+    let byval = context.create_enum_attribute(byval_kind_id, 0);
+    let zeroext = context.create_enum_attribute(zeroext_kind_id, 0);
+    let nounwind = context.create_enum_attribute(nounwind_kind_id, 0);
+    let noinline = context.create_enum_attribute(noinline_kind_id, 0);
+    let target_cpu = context.create_string_attribute("target-cpu", "x86-64");
+
+    fn_value.add_attribute(AttributeLoc::Param(0), sret);
+    fn_value.add_attribute(AttributeLoc::Param(1), byval);
+    fn_value.add_attribute(AttributeLoc::Param(2), zeroext);
+    fn_value.add_attribute(AttributeLoc::Function, nounwind);
+    fn_value.add_attribute(AttributeLoc::Function, noinline);
+    fn_value.add_attribute(AttributeLoc::Function, target_cpu);
+
+    assert_eq!(fn_value.get_enum_attribute(AttributeLoc::Param(0), sret_kind_id), Some(sret));
+    assert_eq!(fn_value.get_enum_attribute(AttributeLoc::Param(1), byval_kind_id), Some(byval));
+    assert_eq!(fn_value.get_enum_attribute(AttributeLoc::Param(2), zeroext_kind_id), Some(zeroext));
+    assert!(fn_value.get_enum_attribute(AttributeLoc::Param(1), sret_kind_id).is_none());
+    assert_eq!(fn_value.get_string_attribute(AttributeLoc::Function, "target-cpu"), Some(target_cpu));
+    assert!(fn_value.get_string_attribute(AttributeLoc::Function, "target-features").is_none());
+
+    assert!(fn_value.verify(false));
+
+    fn_value.remove_attribute(AttributeLoc::Function, noinline);
+    fn_value.remove_attribute(AttributeLoc::Function, target_cpu);
+    fn_value.remove_enum_attribute(AttributeLoc::Param(0), sret_kind_id);
+    fn_value.remove_string_attribute(AttributeLoc::Param(1), "not-present");
+
+    assert_eq!(fn_value.get_enum_attribute(AttributeLoc::Function, nounwind_kind_id), Some(nounwind));
+    assert!(fn_value.get_enum_attribute(AttributeLoc::Function, noinline_kind_id).is_none());
+    assert!(fn_value.get_string_attribute(AttributeLoc::Function, "target-cpu").is_none());
+    assert!(fn_value.get_enum_attribute(AttributeLoc::Param(0), sret_kind_id).is_none());
+}
+
+// Listing and counting attributes is only available from LLVM 4.0 onwards
+#[cfg(not(feature = "llvm3-9"))]
+#[test]
+fn test_function_attribute_lists() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    let void_type = context.void_type();
+    let i32_ptr_type = i32_type.ptr_type(AddressSpace::Generic);
+    let fn_type = void_type.fn_type(&[&i32_ptr_type, &i32_type], false);
+    let fn_value = module.add_function("my_fn", &fn_type, None);
+
+    let sret_kind_id = Attribute::get_named_enum_kind_id("sret");
+    let nounwind_kind_id = Attribute::get_named_enum_kind_id("nounwind");
+
+    let sret = context.create_enum_attribute(sret_kind_id, 0);
+    let zeroext = context.create_enum_attribute(Attribute::get_named_enum_kind_id("zeroext"), 0);
+    let nounwind = context.create_enum_attribute(nounwind_kind_id, 0);
+    let target_cpu = context.create_string_attribute("target-cpu", "x86-64");
+
+    for loc in &[AttributeLoc::Function, AttributeLoc::Return, AttributeLoc::Param(0)] {
+        assert_eq!(fn_value.count_attributes(*loc), 0);
+        assert!(fn_value.get_attributes(*loc).is_empty());
+    }
+
+    fn_value.add_attribute(AttributeLoc::Param(0), sret);
+    fn_value.add_attribute(AttributeLoc::Param(1), zeroext);
+    fn_value.add_attribute(AttributeLoc::Function, nounwind);
+    fn_value.add_attribute(AttributeLoc::Function, target_cpu);
+
+    assert_eq!(fn_value.get_attributes(AttributeLoc::Param(0)), vec![sret]);
+    assert_eq!(fn_value.get_attributes(AttributeLoc::Param(1)), vec![zeroext]);
+    assert_eq!(fn_value.count_attributes(AttributeLoc::Function), 2);
+    assert_eq!(fn_value.count_attributes(AttributeLoc::Return), 0);
+
+    fn_value.remove_enum_attribute(AttributeLoc::Function, nounwind_kind_id);
+    fn_value.remove_enum_attribute(AttributeLoc::Param(0), sret_kind_id);
+
+    assert_eq!(fn_value.get_attributes(AttributeLoc::Function), vec![target_cpu]);
+    assert_eq!(fn_value.count_attributes(AttributeLoc::Param(0)), 0);
+}
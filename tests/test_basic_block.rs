@@ -1,5 +1,6 @@
 extern crate inkwell;
 
+use self::inkwell::basic_block::BasicBlock;
 use self::inkwell::context::Context;
 use self::inkwell::values::InstructionOpcode;
 
@@ -65,9 +66,7 @@ fn test_basic_block_ordering() {
     assert_eq!(bb4, basic_block4);
     assert!(basic_block3.get_previous_basic_block().is_none());
 
-    unsafe {
-        bb4.delete();
-    }
+    assert!(bb4.delete().is_ok());
 
     let bb2 = basic_block5.get_previous_basic_block().unwrap();
 
@@ -167,3 +166,291 @@ fn test_no_parent() {
 
     assert!(basic_block.get_parent().is_none());
 }
+
+#[test]
+fn test_iterators() {
+    let context = Context::create();
+    let module = context.create_module("test");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let function = module.add_function("testing", &fn_type, None);
+
+    assert!(function.basic_blocks().next().is_none());
+    assert!(function.instructions().next_back().is_none());
+
+    let bb1 = function.append_basic_block("bb1");
+    let bb2 = function.append_basic_block("bb2");
+    let bb3 = function.append_basic_block("bb3");
+
+    assert!(bb3.instructions().next().is_none());
+
+    builder.position_at_end(&bb1);
+    builder.build_alloca(&i32_type, "a");
+    builder.build_alloca(&i32_type, "b");
+    builder.build_unconditional_branch(&bb2);
+    builder.position_at_end(&bb2);
+    builder.build_alloca(&i32_type, "c");
+    builder.build_unconditional_branch(&bb3);
+    builder.position_at_end(&bb3);
+    builder.build_return(None);
+
+    let basic_blocks: Vec<_> = function.basic_blocks().collect();
+    let reversed: Vec<_> = function.basic_blocks().rev().collect();
+
+    assert_eq!(basic_blocks, function.get_basic_blocks());
+    assert_eq!(reversed.len(), 3);
+    assert_eq!(reversed[0], bb3);
+    assert_eq!(reversed[2], bb1);
+
+    let mut bb1_instructions = bb1.instructions();
+
+    assert_eq!(bb1_instructions.next().unwrap().get_opcode(), InstructionOpcode::Alloca);
+    assert_eq!(bb1_instructions.next_back().unwrap().get_opcode(), InstructionOpcode::Br);
+    assert_eq!(bb1_instructions.next().unwrap().get_opcode(), InstructionOpcode::Alloca);
+    assert!(bb1_instructions.next().is_none());
+    assert!(bb1_instructions.next_back().is_none());
+
+    let opcodes: Vec<_> = function.instructions().map(|instruction| instruction.get_opcode()).collect();
+    let reversed_opcodes: Vec<_> = function.instructions().rev().map(|instruction| instruction.get_opcode()).collect();
+
+    assert_eq!(opcodes, vec![InstructionOpcode::Alloca, InstructionOpcode::Alloca, InstructionOpcode::Br, InstructionOpcode::Alloca, InstructionOpcode::Br, InstructionOpcode::Return]);
+    assert_eq!(reversed_opcodes, opcodes.iter().rev().cloned().collect::<Vec<_>>());
+
+    // Both ends meet in the middle without yielding anything twice
+    let mut instructions = function.instructions();
+    let mut count = 0;
+
+    while let Some(_) = instructions.next() {
+        count += 1;
+
+        if instructions.next_back().is_some() {
+            count += 1;
+        }
+    }
+
+    assert_eq!(count, 6);
+
+    // The current item may be erased mid-iteration, in either direction
+    for instruction in function.instructions() {
+        if instruction.get_opcode() == InstructionOpcode::Alloca {
+            instruction.erase().unwrap();
+        }
+    }
+
+    assert_eq!(function.instructions().count(), 3);
+
+    for basic_block in function.basic_blocks().rev() {
+        if basic_block == bb3 {
+            continue;
+        }
+
+        for instruction in basic_block.instructions().rev() {
+            instruction.erase().unwrap();
+        }
+    }
+
+    assert_eq!(bb1.instructions().count(), 0);
+    assert_eq!(bb2.instructions().count(), 0);
+    assert_eq!(function.instructions().count(), 1);
+}
+
+#[test]
+fn test_name_split_and_delete() {
+    let context = Context::create();
+    let module = context.create_module("test");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let bool_type = context.bool_type();
+    let fn_type = i32_type.fn_type(&[&bool_type, &i32_type], false);
+    let function = module.add_function("testing", &fn_type, None);
+    let entry = function.append_basic_block("entry");
+    let then_block = function.append_basic_block("then");
+    let merge = function.append_basic_block("merge");
+
+    assert_eq!(*entry.get_name(), *CString::new("entry").unwrap());
+
+    entry.set_name("start");
+
+    assert_eq!(*entry.get_name(), *CString::new("start").unwrap());
+
+    let condition = function.get_first_param().unwrap().into_int_value();
+    let x = function.get_nth_param(1).unwrap().into_int_value();
+    let one = i32_type.const_int(1, false);
+    let two = i32_type.const_int(2, false);
+
+    builder.position_at_end(&entry);
+
+    let sum = builder.build_int_add(&x, &one, "sum");
+    let product = builder.build_int_mul(&sum, &two, "product");
+
+    builder.build_conditional_branch(&condition, &then_block, &merge);
+    builder.position_at_end(&then_block);
+    builder.build_unconditional_branch(&merge);
+    builder.position_at_end(&merge);
+
+    let phi = builder.build_phi(&i32_type, "phi");
+
+    phi.add_incoming(&[(&product, &entry), (&one, &then_block)]);
+    builder.build_return(Some(&phi.as_basic_value()));
+
+    assert!(function.verify(false));
+
+    // Splitting must happen within the block, and not at a phi
+    let product_instruction = product.as_instruction().unwrap();
+
+    assert!(merge.split_at(&product_instruction, "tail").is_err());
+    assert!(merge.split_at(&phi.as_instruction(), "tail").is_err());
+
+    let tail = entry.split_at(&product_instruction, "tail").unwrap();
+
+    assert_eq!(*tail.get_name(), *CString::new("tail").unwrap());
+    assert_eq!(entry.get_next_basic_block().unwrap(), tail);
+    assert_eq!(tail.get_next_basic_block().unwrap(), then_block);
+    assert_eq!(entry.instructions().count(), 2);
+    assert_eq!(entry.get_terminator().unwrap().get_opcode(), InstructionOpcode::Br);
+    assert_eq!(tail.get_first_instruction().unwrap(), product_instruction);
+    assert_eq!(product_instruction.get_parent().unwrap(), tail);
+    assert_eq!(*product.get_name(), *CString::new("product").unwrap());
+    assert_eq!(tail.instructions().count(), 2);
+
+    // The phi in the successor now refers to the new block instead
+    let new_phi = merge.get_first_instruction().unwrap().as_phi().unwrap();
+
+    assert_eq!(*new_phi.get_name(), *CString::new("phi").unwrap());
+    assert_eq!(new_phi.count_incoming(), 2);
+    assert_eq!(new_phi.get_incoming(0).unwrap().1, tail);
+    assert_eq!(new_phi.get_incoming(1).unwrap().1, then_block);
+    assert!(function.verify(false));
+
+    // Blocks which are still branched to, or whose values are used elsewhere, can't be deleted
+    assert!(then_block.delete().is_err());
+
+    let orphan = context.append_basic_block(&function, "orphan");
+
+    orphan.remove_from_function();
+
+    assert!(orphan.delete().is_err());
+
+    let unused = function.append_basic_block("unused");
+
+    builder.position_at_end(&unused);
+    builder.build_unreachable();
+
+    assert!(unused.delete().is_ok());
+    assert_eq!(function.count_basic_blocks(), 4);
+    assert!(function.verify(false));
+}
+
+#[test]
+fn test_successors_and_predecessors() {
+    use self::inkwell::IntPredicate;
+
+    let names = |basic_blocks: Vec<BasicBlock>| -> Vec<String> {
+        basic_blocks.iter().map(|basic_block| basic_block.get_name().to_string_lossy().into_owned()).collect()
+    };
+    let context = Context::create();
+    let module = context.create_module("test");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[&i32_type], false);
+    let function = module.add_function("testing", &fn_type, None);
+    let entry = function.append_basic_block("entry");
+    let check = function.append_basic_block("check");
+    let one = function.append_basic_block("one");
+    let other = function.append_basic_block("other");
+    let exit = function.append_basic_block("exit");
+    let param = function.get_first_param().unwrap().into_int_value();
+
+    assert!(entry.successors().is_empty());
+    assert!(entry.predecessors().is_empty());
+
+    builder.position_at_end(&entry);
+
+    let switch = builder.build_switch(&param, &other, &[(&i32_type.const_int(1, false), &one), (&i32_type.const_int(2, false), &one), (&i32_type.const_int(3, false), &check)]);
+
+    builder.position_at_end(&check);
+
+    let is_zero = builder.build_int_compare(IntPredicate::EQ, &param, &i32_type.const_int(0, false), "is_zero");
+    let branch = builder.build_conditional_branch(&is_zero, &exit, &other);
+
+    builder.position_at_end(&one);
+
+    let jump = builder.build_unconditional_branch(&exit);
+
+    builder.position_at_end(&other);
+    builder.build_unconditional_branch(&exit);
+    builder.position_at_end(&exit);
+
+    let ret = builder.build_return(Some(&param));
+
+    assert_eq!(switch.count_successors(), 4);
+    assert_eq!(switch.get_successor(0).as_ref(), Some(&other));
+    assert_eq!(switch.get_successor(1).as_ref(), Some(&one));
+    assert_eq!(switch.get_successor(3).as_ref(), Some(&check));
+    assert!(switch.get_successor(4).is_none());
+    assert_eq!(names(entry.successors()), vec!["other", "one", "check"]);
+
+    assert_eq!(branch.count_successors(), 2);
+    assert_eq!(names(check.successors()), vec!["exit", "other"]);
+    assert_eq!(names(one.successors()), vec!["exit"]);
+    assert_eq!(ret.count_successors(), 0);
+    assert!(exit.successors().is_empty());
+    assert_eq!(is_zero.as_instruction().unwrap().count_successors(), 0);
+
+    let mut exit_predecessors = names(exit.predecessors());
+
+    exit_predecessors.sort();
+
+    assert_eq!(exit_predecessors, vec!["check", "one", "other"]);
+    assert_eq!(names(one.predecessors()), vec!["entry"]);
+    assert!(entry.predecessors().is_empty());
+    assert!(function.verify(false));
+
+    // Redirect the branches so that "one" is no longer reachable
+    assert!(switch.set_successor(1, &exit));
+    assert!(switch.set_successor(2, &exit));
+    assert!(!switch.set_successor(4, &exit));
+    assert!(branch.set_successor(1, &one));
+    assert!(!jump.set_successor(1, &other));
+
+    assert_eq!(names(entry.successors()), vec!["other", "exit", "check"]);
+    assert_eq!(names(check.successors()), vec!["exit", "one"]);
+    assert_eq!(names(one.predecessors()), vec!["check"]);
+    assert!(function.verify(false));
+}
+
+#[test]
+fn test_delete_block_feeding_phi() {
+    let context = Context::create();
+    let module = context.create_module("test");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[], false);
+    let function = module.add_function("testing", &fn_type, None);
+    let entry = function.append_basic_block("entry");
+    let dead = function.append_basic_block("dead");
+    let exit = function.append_basic_block("exit");
+    let zero = i32_type.const_int(0, false);
+    let one = i32_type.const_int(1, false);
+
+    builder.position_at_end(&entry);
+    builder.build_unconditional_branch(&exit);
+
+    // Nothing branches to dead, but the phi in its successor still names it
+    builder.position_at_end(&dead);
+    builder.build_unconditional_branch(&exit);
+    builder.position_at_end(&exit);
+
+    let phi = builder.build_phi(&i32_type, "phi");
+
+    phi.add_incoming(&[(&zero, &entry), (&one, &dead)]);
+    builder.build_return(Some(&phi.as_basic_value()));
+
+    assert!(function.verify(false));
+    assert!(dead.delete().is_err());
+    assert_eq!(function.count_basic_blocks(), 3);
+    assert_eq!(*phi.get_incoming(1).unwrap().1.get_name(), *CString::new("dead").unwrap());
+    assert!(function.verify(false));
+}
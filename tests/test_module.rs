@@ -195,3 +195,757 @@ fn test_owned_module_dropped_ee_and_context() {
 
     // Context and EE will live on in the module until here
 }
+
+#[test]
+fn test_linking_modules() {
+    let context = Context::create();
+    let module = context.create_module("mod");
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let fn_val = module.add_function("f", &fn_type, None);
+    let module2 = context.create_module("mod2");
+
+    // Unowned module links in unowned (empty) module
+    assert!(module.link_in_module(module2).is_ok());
+    assert_eq!(module.get_function("f"), Some(fn_val));
+    assert!(module.get_function("f2").is_none());
+
+    let module3 = context.create_module("mod3");
+    let fn_val2 = module3.add_function("f2", &fn_type, None);
+
+    // Unowned module links in unowned module
+    assert!(module.link_in_module(module3).is_ok());
+    assert_eq!(module.get_function("f"), Some(fn_val));
+
+    // fn_val2 is no longer the same instance of f2
+    assert_ne!(module.get_function("f2"), Some(fn_val2));
+
+    Target::initialize_native(&InitializationConfig::default()).unwrap();
+
+    let module4 = context.create_module("mod4");
+    let module5 = context.create_module("mod5");
+
+    module5.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    // Unowned module fails to link in owned module
+    assert!(module4.link_in_module(module5).is_err());
+}
+
+#[test]
+fn test_linking_modules_duplicate_symbol() {
+    let context = Context::create();
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let module = context.create_module("mod");
+    let module2 = context.create_module("mod2");
+
+    for module in &[&module, &module2] {
+        let function = module.add_function("f", &fn_type, None);
+        let entry = context.append_basic_block(&function, "entry");
+
+        builder.position_at_end(&entry);
+        builder.build_return(None);
+    }
+
+    let err = module.link_in_module(module2).unwrap_err();
+
+    assert!(err.to_string().contains("f"));
+}
+
+#[test]
+fn test_linking_modules_different_contexts() {
+    let context = Context::create();
+    let context2 = Context::create();
+    let module = context.create_module("mod");
+    let module2 = context2.create_module("mod2");
+
+    assert!(module.link_in_module(module2).is_err());
+}
+
+#[test]
+fn test_parse_bitcode_from_buffer() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let function = module.add_function("my_fn", &fn_type, None);
+    let basic_block = context.append_basic_block(&function, "entry");
+
+    builder.position_at_end(&basic_block);
+    builder.build_return(None);
+
+    let buffer = module.write_bitcode_to_memory();
+    let module2 = Module::parse_bitcode_from_buffer(&buffer, &context).unwrap();
+
+    assert_eq!(*module2.get_context(), context);
+    assert_eq!(module2.get_function("my_fn").unwrap().print_to_string(), function.print_to_string());
+
+    // The buffer is still usable after parsing
+    let module3 = Module::parse_bitcode_from_buffer(&buffer, &context).unwrap();
+
+    assert!(module3.get_function("my_fn").is_some());
+
+    let garbage = MemoryBuffer::create_from_memory_range("garbage bitcode", "my_bc");
+
+    assert!(Module::parse_bitcode_from_buffer(&garbage, &context).is_err());
+}
+
+#[test]
+fn test_parse_bitcode_from_path() {
+    let mut path = temp_dir();
+
+    path.push("temp_parse.bc");
+
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+
+    module.add_function("my_fn", &fn_type, None);
+
+    assert!(module.write_bitcode_to_path(&path));
+
+    let module2 = Module::parse_bitcode_from_path(&path, &context).unwrap();
+    let module3 = Module::parse_bitcode_from_path_lazily(&path, &context).unwrap();
+
+    assert!(module2.get_function("my_fn").is_some());
+    assert!(module3.get_function("my_fn").is_some());
+
+    remove_file(&path).unwrap();
+
+    assert!(Module::parse_bitcode_from_path(&path, &context).is_err());
+}
+
+#[test]
+fn test_parse_bitcode_from_buffer_lazily() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let function = module.add_function("my_fn", &fn_type, None);
+    let basic_block = context.append_basic_block(&function, "entry");
+
+    builder.position_at_end(&basic_block);
+    builder.build_return(None);
+
+    let buffer = module.write_bitcode_to_memory();
+    let lazy_module = Module::parse_bitcode_from_buffer_lazily(buffer, &context).unwrap();
+
+    assert!(lazy_module.get_function("my_fn").is_some());
+
+    // Linking forces the body to be materialized
+    let linked_module = context.create_module("linked");
+
+    assert!(linked_module.link_in_module(lazy_module).is_ok());
+    assert_eq!(linked_module.get_function("my_fn").unwrap().print_to_string(), function.print_to_string());
+
+    let garbage = MemoryBuffer::create_from_memory_range("garbage bitcode", "my_bc");
+
+    assert!(Module::parse_bitcode_from_buffer_lazily(garbage, &context).is_err());
+}
+
+#[test]
+fn test_create_module_from_ir_parse_error() {
+    let context = Context::create();
+    let ir = "define void @my_fn() {\nentry:\n  ret i32 foo\n}\n";
+    let memory_buffer = MemoryBuffer::create_from_memory_range(ir, "my_fixture.ll");
+    let err = context.create_module_from_ir(memory_buffer).unwrap_err();
+
+    assert_eq!(err.get_filename(), "my_fixture.ll");
+    assert_eq!(err.get_line(), Some(3));
+    assert!(err.get_column().is_some());
+    assert_eq!(err.get_source_line(), Some("  ret i32 foo"));
+    assert!(!err.get_message().is_empty());
+    assert!(!err.get_message().contains("my_fixture.ll"));
+    assert!(err.get_diagnostic().starts_with("my_fixture.ll:3:"));
+}
+
+#[test]
+fn test_create_module_from_ir_parses_valid_ir() {
+    let context = Context::create();
+    let ir = "define void @my_fn() {\nentry:\n  ret void\n}\n";
+    let memory_buffer = MemoryBuffer::create_from_memory_range(ir, "my_fixture.ll");
+    let module = context.create_module_from_ir(memory_buffer).unwrap();
+
+    assert!(module.get_function("my_fn").is_some());
+}
+
+#[test]
+fn test_function_and_global_iterators() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let void_type = context.void_type();
+    let i32_type = context.i32_type();
+    let fn_type = void_type.fn_type(&[], false);
+
+    assert_eq!(module.functions().count(), 0);
+    assert_eq!(module.globals().count(), 0);
+
+    let fn1 = module.add_function("fn1", &fn_type, None);
+    let fn2 = module.add_function("fn2", &fn_type, None);
+    let fn3 = module.add_function("fn3", &fn_type, None);
+    let global1 = module.add_global(&i32_type, None, "global1");
+    let global2 = module.add_global(&i32_type, None, "global2");
+
+    assert_eq!(module.functions().collect::<Vec<_>>(), vec![fn1, fn2, fn3]);
+    assert_eq!(module.functions().rev().collect::<Vec<_>>(), vec![fn3, fn2, fn1]);
+    assert_eq!(module.globals().collect::<Vec<_>>(), vec![global1, global2]);
+    assert_eq!(module.globals().rev().collect::<Vec<_>>(), vec![global2, global1]);
+
+    // Both ends meet in the middle without yielding an item twice
+    let mut iter = module.functions();
+
+    assert_eq!(iter.next(), Some(fn1));
+    assert_eq!(iter.next_back(), Some(fn3));
+    assert_eq!(iter.next(), Some(fn2));
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.next(), None);
+
+    // Functions added while iterating forwards are visited too
+    let mut names = Vec::new();
+
+    for function in module.functions() {
+        if function == fn1 {
+            module.add_function("fn4", &fn_type, None);
+        }
+
+        names.push(function.get_name().to_str().unwrap().to_string());
+    }
+
+    assert_eq!(names, vec!["fn1", "fn2", "fn3", "fn4"]);
+}
+
+#[test]
+fn test_global_aliases() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let i32_type = context.i32_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let function = module.add_function("my_fn", &fn_type, None);
+    let entry = context.append_basic_block(&function, "entry");
+
+    builder.position_at_end(&entry);
+    builder.build_return(None);
+
+    let global = module.add_global(&i32_type, None, "my_global");
+
+    global.set_initializer(&i32_type.const_int(42, false));
+
+    assert!(module.get_global_aliases().is_empty());
+    assert!(!global.is_alias());
+    assert!(global.get_aliasee().is_none());
+
+    let fn_alias = module.add_global_alias(&fn_type, &function.as_global_value().as_pointer_value(), "my_fn_alias");
+    let global_alias = module.add_global_alias(&i32_type, &global.as_pointer_value(), "my_global_alias");
+    let alias_alias = module.add_global_alias(&i32_type, &global_alias.as_pointer_value(), "my_global_alias_alias");
+
+    assert!(fn_alias.is_alias());
+    assert_eq!(fn_alias.get_name().to_str(), Ok("my_fn_alias"));
+    assert_eq!(global_alias.get_aliasee(), Some(global.as_pointer_value()));
+    assert_eq!(alias_alias.get_aliasee(), Some(global_alias.as_pointer_value()));
+
+    let mut aliases = module.get_global_aliases();
+
+    aliases.sort_by_key(|alias| alias.get_name().to_str().unwrap().to_string());
+
+    assert_eq!(aliases, vec![fn_alias, global_alias, alias_alias]);
+
+    let global2 = module.add_global(&i32_type, None, "my_global2");
+
+    global2.set_initializer(&i32_type.const_int(7, false));
+    assert!(global_alias.set_aliasee(&global2.as_pointer_value()));
+    assert!(!global2.set_aliasee(&global.as_pointer_value()));
+    assert!(!fn_alias.set_aliasee(&global2.as_pointer_value()));
+    assert_eq!(global_alias.get_aliasee(), Some(global2.as_pointer_value()));
+    assert_eq!(fn_alias.get_aliasee(), Some(function.as_global_value().as_pointer_value()));
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_module_flags() {
+    use self::inkwell::module::FlagBehavior;
+
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let i32_type = context.i32_type();
+
+    assert!(module.get_flag("PIC Level").is_none());
+    assert!(module.get_flag_behavior("PIC Level").is_none());
+
+    module.add_flag(FlagBehavior::Warning, "Debug Info Version", &i32_type.const_int(3, false));
+    module.add_flag(FlagBehavior::Error, "PIC Level", &i32_type.const_int(2, false));
+    module.add_flag(FlagBehavior::Error, "wchar_size", &i32_type.const_int(4, false));
+    module.add_flag(FlagBehavior::Override, "my_abi_version", &i32_type.const_int(7, false));
+
+    let require = context.metadata_node(&[]);
+
+    module.add_metadata_flag(FlagBehavior::AppendUnique, "my_libs", &require);
+
+    assert_eq!(module.get_global_metadata_size("llvm.module.flags"), 5);
+    assert_eq!(module.get_flag("Debug Info Version").unwrap().into_int_value(), i32_type.const_int(3, false));
+    assert_eq!(module.get_flag("PIC Level").unwrap().into_int_value(), i32_type.const_int(2, false));
+    assert_eq!(module.get_flag("wchar_size").unwrap().into_int_value(), i32_type.const_int(4, false));
+    assert_eq!(module.get_flag("my_abi_version").unwrap().into_int_value(), i32_type.const_int(7, false));
+    assert!(module.get_flag("my_libs").unwrap().is_metadata_value());
+    assert_eq!(module.get_flag_behavior("Debug Info Version"), Some(FlagBehavior::Warning));
+    assert_eq!(module.get_flag_behavior("PIC Level"), Some(FlagBehavior::Error));
+    assert_eq!(module.get_flag_behavior("my_abi_version"), Some(FlagBehavior::Override));
+    assert_eq!(module.get_flag_behavior("my_libs"), Some(FlagBehavior::AppendUnique));
+    assert!(module.verify().is_ok());
+
+    // Flags survive a bitcode round trip, as used when caching modules
+    let buffer = module.write_bitcode_to_memory();
+    let module2 = Module::parse_bitcode_from_buffer(&buffer, &context).unwrap();
+
+    assert_eq!(module2.get_flag("my_abi_version").unwrap().into_int_value(), i32_type.const_int(7, false));
+    assert_eq!(module2.get_flag_behavior("my_abi_version"), Some(FlagBehavior::Override));
+}
+
+#[test]
+fn test_malformed_module_flag_behaviors() {
+    let context = Context::create();
+    let ir = "!llvm.module.flags = !{!0, !1, !2}\n\
+              !0 = !{i32 42, !\"out_of_range\", i32 1}\n\
+              !1 = !{!\"oops\", !\"not_an_int\", i32 1}\n\
+              !2 = !{i128 1, !\"too_wide\", i32 1}\n";
+    let memory_buffer = MemoryBuffer::create_from_memory_range(ir, "my_fixture.ll");
+    let module = context.create_module_from_ir(memory_buffer).unwrap();
+
+    assert!(module.get_flag("out_of_range").is_some());
+    assert!(module.get_flag_behavior("out_of_range").is_none());
+    assert!(module.get_flag_behavior("not_an_int").is_none());
+    assert!(module.get_flag_behavior("too_wide").is_none());
+}
+
+#[test]
+fn test_module_name() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+
+    assert_eq!(module.get_name().to_str(), Ok("my_module"));
+
+    module.set_name("my_renamed_module");
+
+    assert_eq!(module.get_name().to_str(), Ok("my_renamed_module"));
+    assert_eq!(module.clone().get_name().to_str(), Ok("my_renamed_module"));
+}
+
+#[test]
+fn test_clone_into_other_context() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let function = module.add_function("my_fn", &fn_type, None);
+    let entry = context.append_basic_block(&function, "entry");
+
+    builder.position_at_end(&entry);
+    builder.build_return(None);
+
+    module.set_target("x86_64-pc-linux-gnu");
+
+    let other_context = Context::create();
+    let other_module = module.clone_into(&other_context);
+
+    assert_eq!(*other_module.get_context(), other_context);
+    assert_ne!(*other_module.get_context(), context);
+    assert_eq!(other_module.get_name().to_str(), Ok("my_module"));
+    assert_eq!(other_module.get_target(), module.get_target());
+    assert_eq!(other_module.get_function("my_fn").unwrap().print_to_string(), function.print_to_string());
+    assert!(other_module.verify().is_ok());
+
+    // The copy is independent of the original
+    drop(module);
+    drop(context);
+
+    assert!(other_module.get_function("my_fn").is_some());
+}
+
+#[test]
+fn test_global_constructors_and_destructors() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let i32_type = context.i32_type();
+    let fn_type = void_type.fn_type(&[], false);
+
+    let add_empty_fn = |module: &Module, name: &str| {
+        let function = module.add_function(name, &fn_type, None);
+        let entry = context.append_basic_block(&function, "entry");
+
+        builder.position_at_end(&entry);
+        builder.build_return(None);
+
+        function
+    };
+
+    let ctor1 = add_empty_fn(&module, "ctor1");
+    let ctor2 = add_empty_fn(&module, "ctor2");
+    let dtor = add_empty_fn(&module, "dtor");
+    let data = module.add_global(&i32_type, None, "data");
+
+    data.set_initializer(&i32_type.const_int(0, false));
+
+    assert!(module.get_global("llvm.global_ctors").is_none());
+
+    assert!(module.add_global_constructor(&ctor1, 65535, None).is_ok());
+
+    let ctors = module.get_global("llvm.global_ctors").unwrap();
+
+    assert_eq!(ctors.get_initializer().unwrap().into_array_value().get_type().len(), 1);
+
+    assert!(module.add_global_constructor(&ctor2, 0, Some(&data)).is_ok());
+    assert!(module.add_global_destructor(&dtor, 65535, None).is_ok());
+
+    let ctors = module.get_global("llvm.global_ctors").unwrap();
+    let dtors = module.get_global("llvm.global_dtors").unwrap();
+
+    assert_eq!(ctors.get_initializer().unwrap().into_array_value().get_type().len(), 2);
+    assert_eq!(dtors.get_initializer().unwrap().into_array_value().get_type().len(), 1);
+    assert_eq!(module.globals().filter(|global| global.get_name().to_str() == Ok("llvm.global_ctors")).count(), 1);
+    assert!(module.verify().is_ok());
+
+    // Constructor lists are merged when linking
+    let module2 = context.create_module("my_module2");
+    let ctor3 = add_empty_fn(&module2, "ctor3");
+
+    assert!(module2.add_global_constructor(&ctor3, 100, None).is_ok());
+
+    assert!(module.link_in_module(module2).is_ok());
+
+    let ctors = module.get_global("llvm.global_ctors").unwrap();
+
+    assert_eq!(ctors.get_initializer().unwrap().into_array_value().get_type().len(), 3);
+    assert!(module.verify().is_ok());
+
+    // And can still be extended afterwards
+    let ctor4 = add_empty_fn(&module, "ctor4");
+
+    assert!(module.add_global_constructor(&ctor4, 65535, None).is_ok());
+
+    let ctors = module.get_global("llvm.global_ctors").unwrap();
+
+    assert_eq!(ctors.get_initializer().unwrap().into_array_value().get_type().len(), 4);
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_global_constructor_layouts() {
+    let context = Context::create();
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[&i32_type], false);
+
+    // Constructors with other signatures are cast to void ()*
+    let module = context.create_module("my_module");
+    let ctor = module.add_function("ctor", &fn_type, None);
+    let entry = context.append_basic_block(&ctor, "entry");
+
+    builder.position_at_end(&entry);
+    builder.build_return(Some(&i32_type.const_int(0, false)));
+
+    assert!(module.add_global_constructor(&ctor, 65535, None).is_ok());
+    assert!(module.verify().is_ok());
+
+    // Older two field lists are upgraded rather than mixed with three field entries
+    let ir = "@llvm.global_ctors = appending global [1 x { i32, void ()* }] [{ i32, void ()* } { i32 65535, void ()* @old_ctor }]\n\
+              define void @old_ctor() {\n  ret void\n}\n";
+    let memory_buffer = MemoryBuffer::create_from_memory_range(ir, "my_fixture.ll");
+    let module = context.create_module_from_ir(memory_buffer).unwrap();
+    let ctor = module.add_function("ctor", &fn_type, None);
+
+    assert!(module.add_global_constructor(&ctor, 100, None).is_ok());
+
+    let ctors = module.get_global("llvm.global_ctors").unwrap().get_initializer().unwrap().into_array_value();
+
+    assert_eq!(ctors.get_type().len(), 2);
+    assert!(module.print_to_string().to_string().contains("[2 x { i32, void ()*, i8* }]"));
+    assert!(module.verify().is_ok());
+
+    // Anything else is rejected, leaving the list alone
+    let ir = "@llvm.global_ctors = appending global [1 x { i64, void ()*, i8* }] [{ i64, void ()*, i8* } { i64 65535, void ()* @old_ctor, i8* null }]\n\
+              define void @old_ctor() {\n  ret void\n}\n";
+    let memory_buffer = MemoryBuffer::create_from_memory_range(ir, "my_fixture.ll");
+    let module = context.create_module_from_ir(memory_buffer).unwrap();
+    let ctor = module.add_function("ctor", &fn_type, None);
+
+    assert!(module.add_global_constructor(&ctor, 100, None).is_err());
+    assert_eq!(module.get_global("llvm.global_ctors").unwrap().get_initializer().unwrap().into_array_value().get_type().len(), 1);
+}
+
+#[test]
+fn test_mark_used() {
+    use self::inkwell::module::Linkage;
+    use self::inkwell::passes::PassManager;
+
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let i32_type = context.i32_type();
+    let fn_type = void_type.fn_type(&[], false);
+
+    let used_fn = module.add_function("used_fn", &fn_type, Some(&Linkage::InternalLinkage));
+    let entry = context.append_basic_block(&used_fn, "entry");
+
+    builder.position_at_end(&entry);
+    builder.build_return(None);
+
+    let unused_fn = module.add_function("unused_fn", &fn_type, Some(&Linkage::InternalLinkage));
+    let entry = context.append_basic_block(&unused_fn, "entry");
+
+    builder.position_at_end(&entry);
+    builder.build_return(None);
+
+    let used_global = module.add_global(&i32_type, None, "used_global");
+    let compiler_used_global = module.add_global(&i32_type, None, "compiler_used_global");
+
+    used_global.set_initializer(&i32_type.const_int(1, false));
+    compiler_used_global.set_initializer(&i32_type.const_int(2, false));
+
+    assert!(module.get_used().is_empty());
+    assert!(module.get_compiler_used().is_empty());
+
+    module.mark_function_used(&used_fn);
+    module.mark_used(&used_global);
+    module.mark_used(&used_global);
+    module.mark_compiler_used(&compiler_used_global);
+    module.mark_function_compiler_used(&used_fn);
+    module.mark_function_compiler_used(&used_fn);
+
+    assert_eq!(module.get_used(), vec![used_fn.as_global_value(), used_global]);
+    assert_eq!(module.get_compiler_used(), vec![compiler_used_global, used_fn.as_global_value()]);
+    assert_eq!(module.get_global("llvm.used").unwrap().get_section().to_str(), Ok("llvm.metadata"));
+    assert!(module.verify().is_ok());
+
+    let pass_manager = PassManager::create_for_module();
+
+    pass_manager.add_global_dce_pass();
+    pass_manager.add_strip_dead_prototypes_pass();
+    pass_manager.run_on_module(&module);
+
+    assert!(module.get_function("used_fn").is_some());
+    assert!(module.get_function("unused_fn").is_none());
+    assert!(module.get_global("used_global").is_some());
+    assert!(module.get_global("compiler_used_global").is_some());
+}
+
+#[test]
+fn test_diff() {
+    let context = Context::create();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[&i32_type, &i32_type], false);
+
+    let build_module = |name: &str, globals_first: bool, local_names: &[&str], use_mul: bool| {
+        let module = context.create_module(name);
+        let builder = context.create_builder();
+
+        if globals_first {
+            module.add_global(&i32_type, None, "my_global");
+        }
+
+        let function = module.add_function("my_fn", &fn_type, None);
+        let entry = context.append_basic_block(&function, local_names[0]);
+        let lhs = function.get_nth_param(0).unwrap().into_int_value();
+        let rhs = function.get_nth_param(1).unwrap().into_int_value();
+
+        lhs.set_name(local_names[1]);
+        rhs.set_name(local_names[2]);
+        builder.position_at_end(&entry);
+
+        let result = if use_mul {
+            builder.build_int_mul(&lhs, &rhs, local_names[3])
+        } else {
+            builder.build_int_add(&lhs, &rhs, local_names[3])
+        };
+
+        builder.build_return(Some(&result));
+
+        if !globals_first {
+            module.add_global(&i32_type, None, "my_global");
+        }
+
+        module
+    };
+
+    let module = build_module("mod", true, &["entry", "a", "b", "sum"], false);
+    let renamed_module = build_module("mod2", false, &["start", "", "y", ""], false);
+    let changed_module = build_module("mod3", true, &["entry", "a", "b", "sum"], true);
+
+    assert!(module.diff(&module).is_empty());
+    assert!(module.diff(&renamed_module).is_empty());
+    assert_eq!(module.diff(&changed_module), vec![
+        "function @my_fn block 0: - `%3 = add i32 %0, %1`",
+        "function @my_fn block 0: + `%3 = mul i32 %0, %1`",
+    ]);
+
+    let other_module = context.create_module("mod4");
+
+    other_module.add_function("other_fn", &fn_type, None);
+
+    assert_eq!(module.diff(&other_module), vec![
+        "global @my_global only exists in the left module",
+        "function @my_fn only exists in the left module",
+        "function @other_fn only exists in the right module",
+    ]);
+}
+
+#[test]
+fn test_diff_ignores_local_names_only() {
+    // Named types are unique per context, so each module gets its own
+    let parse = |context: &Context, locals: (&str, &str, &str, &str)| {
+        let ir = format!("%foo = type {{ i32 }}\n\
+                          define i32 @my_fn(%foo* {0}) {{\n\
+                          {1}:\n  \
+                          {2} = getelementptr %foo, %foo* {0}, i32 0, i32 0\n  \
+                          {3} = load i32, i32* {2}\n  \
+                          ret i32 {3}\n\
+                          }}\n", locals.0, locals.1, locals.2, locals.3);
+        let memory_buffer = MemoryBuffer::create_from_memory_range(&ir, "my_fixture.ll");
+
+        context.create_module_from_ir(memory_buffer).unwrap()
+    };
+
+    // A local sharing its name with a named type, and quoted local names
+    let context = Context::create();
+    let other_context = Context::create();
+    let module = parse(&context, ("%foo", "entry", "%\"my field\"", "%\"foo\\22bar\""));
+    let renamed_module = parse(&other_context, ("%p", "start", "%q", "%r"));
+
+    assert!(module.diff(&renamed_module).is_empty());
+    assert!(renamed_module.diff(&module).is_empty());
+
+    // Diffing works on copies, so the original names are kept
+    let function = module.get_function("my_fn").unwrap();
+
+    assert_eq!(function.get_first_param().unwrap().into_pointer_value().get_name().to_str(), Ok("foo"));
+}
+
+#[test]
+fn test_diff_multiple_blocks() {
+    let parse = |context: &Context, case: u32, result: u32| {
+        let ir = format!("define i32 @my_fn(i32 %x) {{\n\
+                          entry:\n  \
+                          switch i32 %x, label %default [\n    \
+                          i32 {0}, label %one\n    \
+                          i32 7, label %one\n  \
+                          ]\n\
+                          one:\n  \
+                          ret i32 {1}\n\
+                          default:\n  \
+                          ret i32 0\n\
+                          }}\n", case, result);
+        let memory_buffer = MemoryBuffer::create_from_memory_range(&ir, "my_fixture.ll");
+
+        context.create_module_from_ir(memory_buffer).unwrap()
+    };
+
+    let context = Context::create();
+    let module = parse(&context, 1, 1);
+    let changed_case_module = parse(&context, 2, 1);
+    let changed_result_module = parse(&context, 1, 2);
+
+    assert!(module.diff(&parse(&context, 1, 1)).is_empty());
+    assert_eq!(module.diff(&changed_case_module).len(), 2);
+    assert!(module.diff(&changed_case_module)[0].starts_with("function @my_fn block 0: - `switch i32 %0, label "));
+    assert!(module.diff(&changed_case_module)[0].ends_with("[ i32 1, label %2 i32 7, label %2 ]`"));
+    assert_eq!(module.diff(&changed_result_module), vec![
+        "function @my_fn block 1: - `ret i32 1`",
+        "function @my_fn block 1: + `ret i32 2`",
+    ]);
+}
+
+#[test]
+fn test_statistics() {
+    use self::inkwell::values::InstructionOpcode;
+
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[], false);
+
+    module.add_global(&i32_type, None, "my_global");
+
+    let callee = module.add_function("callee", &fn_type, None);
+    let function = module.add_function("my_fn", &fn_type, None);
+    let entry = context.append_basic_block(&function, "entry");
+    let exit = context.append_basic_block(&function, "exit");
+
+    builder.position_at_end(&entry);
+
+    let ptr = builder.build_alloca(&i32_type, "ptr");
+    let value = builder.build_call(&callee, &[], "value", false).left().unwrap().into_int_value();
+
+    builder.build_store(&ptr, &value);
+    builder.build_unconditional_branch(&exit);
+    builder.position_at_end(&exit);
+
+    let phi = builder.build_phi(&i32_type, "phi");
+
+    phi.add_incoming(&[(&value, &entry)]);
+    builder.build_return(Some(&phi.as_basic_value()));
+
+    let statistics = module.statistics();
+
+    assert_eq!(statistics.count_functions(), 2);
+    assert_eq!(statistics.count_globals(), 1);
+    assert_eq!(statistics.count_basic_blocks(), 2);
+    assert_eq!(statistics.count_instructions(), 6);
+    assert!(statistics.get_machine_code_size().is_none());
+
+    let callee_statistics = statistics.get_function("callee").unwrap();
+
+    assert_eq!(callee_statistics.count_basic_blocks(), 0);
+    assert_eq!(callee_statistics.count_instructions(), 0);
+
+    let function_statistics = statistics.get_function("my_fn").unwrap();
+
+    assert_eq!(function_statistics.get_name(), "my_fn");
+    assert_eq!(function_statistics.count_basic_blocks(), 2);
+    assert_eq!(function_statistics.count_instructions(), 6);
+    assert_eq!(function_statistics.count_calls(), 1);
+    assert_eq!(function_statistics.count_allocas(), 1);
+    assert_eq!(function_statistics.count_phis(), 1);
+    assert_eq!(function_statistics.count_opcode(InstructionOpcode::Store), 1);
+    assert_eq!(function_statistics.count_opcode(InstructionOpcode::Mul), 0);
+    assert_eq!(function_statistics.get_opcode_counts().len(), 6);
+    assert!(statistics.get_function("nonexistent").is_none());
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_statistics_with_machine_code() {
+    use self::inkwell::targets::{CodeModel, RelocMode, TargetMachine};
+
+    Target::initialize_native(&InitializationConfig::default()).unwrap();
+
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[], false);
+    let function = module.add_function("my_fn", &fn_type, None);
+    let entry = context.append_basic_block(&function, "entry");
+
+    module.add_function("my_declaration", &fn_type, None);
+    builder.position_at_end(&entry);
+    builder.build_return(Some(&i32_type.const_int(42, false)));
+
+    let triple = TargetMachine::get_default_triple().to_str().unwrap();
+    let target = Target::from_triple(triple).unwrap();
+    let target_machine = target.create_target_machine(triple, "", "", OptimizationLevel::None, RelocMode::PIC, CodeModel::Default).unwrap();
+    let statistics = module.statistics_with_machine_code(&target_machine).unwrap();
+    let size = statistics.get_function("my_fn").unwrap().get_machine_code_size().unwrap();
+
+    assert!(size > 0);
+    assert_eq!(statistics.get_machine_code_size(), Some(size));
+    assert!(statistics.get_function("my_declaration").unwrap().get_machine_code_size().is_none());
+}
@@ -325,3 +325,10 @@ fn test_type_copies() {
 
     assert_eq!(i8_type, i8_type_copy);
 }
+
+#[test]
+fn test_address_space_round_trip() {
+    for address_space in &[AddressSpace::Generic, AddressSpace::Global, AddressSpace::Shared, AddressSpace::Const, AddressSpace::Local] {
+        assert_eq!(AddressSpace::from(*address_space as u32), *address_space);
+    }
+}
@@ -2,10 +2,11 @@ extern crate inkwell;
 
 use self::inkwell::{DLLStorageClass, FloatPredicate, GlobalVisibility, ThreadLocalMode, AddressSpace};
 use self::inkwell::context::Context;
+use self::inkwell::memory_buffer::MemoryBuffer;
 use self::inkwell::module::Linkage::*;
-use self::inkwell::types::{StructType, VectorType};
+use self::inkwell::types::{AnyTypeEnum, StructType, VectorType};
 use self::inkwell::values::InstructionOpcode::*;
-use self::inkwell::values::{MetadataValue, FIRST_CUSTOM_METADATA_KIND_ID};
+use self::inkwell::values::{AnyValue, MetadataValue, FIRST_CUSTOM_METADATA_KIND_ID};
 
 use std::ffi::CString;
 
@@ -866,9 +867,7 @@ fn test_globals() {
     // REVIEW: This doesn't seem to work. LLVM bug?
     // assert!(global.is_externally_initialized());
 
-    unsafe {
-        global.delete();
-    }
+    assert!(global.delete().is_ok());
 }
 
 #[test]
@@ -945,3 +944,604 @@ fn test_allocations() {
 
     // assert_eq!(heap_array.get_type().print_to_string(), &*CString::new("i32*").unwrap());
 }
+
+#[test]
+fn test_instruction_operands() {
+    let context = Context::create();
+    let module = context.create_module("ivs");
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let i64_type = context.i64_type();
+    let bool_type = context.bool_type();
+    let fn_type = void_type.fn_type(&[&i64_type, &bool_type], false);
+    let function = module.add_function("my_fn", &fn_type, None);
+    let entry = function.append_basic_block("entry");
+    let then_block = function.append_basic_block("then");
+    let else_block = function.append_basic_block("else");
+
+    builder.position_at_end(&entry);
+
+    let arg1 = function.get_first_param().unwrap().into_int_value();
+    let arg2 = function.get_nth_param(1).unwrap().into_int_value();
+    let seven = i64_type.const_int(7, false);
+    let sum = builder.build_int_add(&arg1, &seven, "sum");
+    let add_instruction = sum.as_instruction().unwrap();
+    let branch_instruction = builder.build_conditional_branch(&arg2, &then_block, &else_block);
+
+    assert_eq!(add_instruction.get_opcode(), Add);
+    assert_eq!(add_instruction.get_num_operands(), 2);
+    assert_eq!(add_instruction.get_operand(0).unwrap().left().unwrap().into_int_value(), arg1);
+    assert_eq!(add_instruction.get_operand(1).unwrap().left().unwrap().into_int_value(), seven);
+    assert!(add_instruction.get_operand(2).is_none());
+
+    // LLVM stores a conditional branch's blocks in reverse order, after its condition
+    assert_eq!(branch_instruction.get_num_operands(), 3);
+    assert_eq!(branch_instruction.get_operand(0).unwrap().left().unwrap().into_int_value(), arg2);
+    assert_eq!(branch_instruction.get_operand(1).unwrap().right().unwrap(), else_block);
+    assert_eq!(branch_instruction.get_operand(2).unwrap().right().unwrap(), then_block);
+
+    let eight = i64_type.const_int(8, false);
+
+    assert!(add_instruction.set_operand(1, &eight));
+    assert!(!add_instruction.set_operand(2, &eight));
+    assert_eq!(add_instruction.get_operand(1).unwrap().left().unwrap().into_int_value(), eight);
+
+    builder.position_at_end(&then_block);
+    builder.build_return(None);
+    builder.position_at_end(&else_block);
+    builder.build_return(None);
+
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_value_uses() {
+    let context = Context::create();
+    let module = context.create_module("uses");
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let i32_type = context.i32_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let function = module.add_function("my_fn", &fn_type, None);
+    let entry = function.append_basic_block("entry");
+
+    builder.position_at_end(&entry);
+
+    let ptr = builder.build_alloca(&i32_type, "ptr");
+    let unused = builder.build_alloca(&i32_type, "unused");
+
+    assert!(ptr.get_first_use().is_none());
+
+    let store = builder.build_store(&ptr, &i32_type.const_int(1, false));
+    let value = builder.build_load(&ptr, "value").into_int_value();
+    let load = value.as_instruction().unwrap();
+    let sum = builder.build_int_add(&value, &value, "sum");
+
+    builder.build_return(None);
+
+    let mut uses = Vec::new();
+    let mut use_ = ptr.get_first_use();
+
+    while let Some(current_use) = use_ {
+        assert_eq!(current_use.get_used_value().unwrap().into_pointer_value(), ptr);
+
+        uses.push((current_use.get_user().unwrap().left().unwrap(), current_use.get_operand_index()));
+        use_ = current_use.get_next_use();
+    }
+
+    assert_eq!(uses.len(), 2);
+    assert_eq!(ptr.uses().count(), 2);
+    assert_eq!(ptr.uses().map(|use_| use_.get_user().unwrap().left().unwrap()).collect::<Vec<_>>(), uses.iter().map(|&(user, _)| user).collect::<Vec<_>>());
+    assert_eq!(unused.uses().count(), 0);
+    assert!(uses.contains(&(store, 1)));
+    assert!(uses.contains(&(load, 0)));
+    assert!(unused.get_first_use().is_none());
+
+    // Each operand referring to the same value is a separate use
+    let first_use = value.get_first_use().unwrap();
+    let second_use = first_use.get_next_use().unwrap();
+
+    assert_eq!(first_use.get_user().unwrap().left().unwrap(), sum.as_instruction().unwrap());
+    assert_eq!(second_use.get_user().unwrap().left().unwrap(), sum.as_instruction().unwrap());
+    assert_ne!(first_use.get_operand_index(), second_use.get_operand_index());
+    assert!(second_use.get_next_use().is_none());
+
+    // Constants, such as a global's initializer, may also be users
+    let global = module.add_global(&i32_type.ptr_type(AddressSpace::Generic), None, "my_global");
+    let target = module.add_global(&i32_type, None, "target");
+
+    global.set_initializer(&target.as_pointer_value());
+
+    let global_use = target.get_first_use().unwrap();
+
+    assert!(global_use.get_user().unwrap().is_right());
+    assert_eq!(global_use.get_operand_index(), 0);
+
+    // Users may be erased while iterating over their uses
+    sum.as_instruction().unwrap().erase().unwrap();
+
+    for use_ in ptr.uses() {
+        use_.get_user().unwrap().left().unwrap().erase().unwrap();
+    }
+
+    assert_eq!(ptr.uses().count(), 0);
+}
+
+#[test]
+fn test_instruction_views() {
+    use self::inkwell::IntPredicate;
+
+    let context = Context::create();
+    let module = context.create_module("views");
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let i32_type = context.i32_type();
+    let f64_type = context.f64_type();
+    let callee_type = i32_type.fn_type(&[&i32_type], false);
+    let callee = module.add_function("callee", &callee_type, None);
+    let fn_type = void_type.fn_type(&[&f64_type], false);
+    let function = module.add_function("my_fn", &fn_type, None);
+    let entry = function.append_basic_block("entry");
+    let then_block = function.append_basic_block("then");
+    let else_block = function.append_basic_block("else");
+
+    builder.position_at_end(&entry);
+
+    let float_arg = function.get_first_param().unwrap().into_float_value();
+    let ptr = builder.build_alloca(&i32_type, "ptr");
+    let seven = i32_type.const_int(7, false);
+    let store = builder.build_store(&ptr, &seven);
+    let value = builder.build_load(&ptr, "value").into_int_value();
+    let call_value = builder.build_call(&callee, &[&value], "call", false).left().unwrap().into_int_value();
+    let int_cmp = builder.build_int_compare(IntPredicate::SLT, &call_value, &seven, "int_cmp");
+    let float_cmp = builder.build_float_compare(FloatPredicate::OGE, &float_arg, &f64_type.const_float(1.0), "float_cmp");
+    let branch = builder.build_conditional_branch(&int_cmp, &then_block, &else_block);
+
+    builder.position_at_end(&then_block);
+
+    let unconditional_branch = builder.build_unconditional_branch(&else_block);
+
+    builder.position_at_end(&else_block);
+    builder.build_return(None);
+
+    assert!(module.verify().is_ok());
+
+    let alloca = ptr.as_instruction().unwrap().as_alloca().unwrap();
+
+    assert_eq!(alloca.get_allocated_type().into_int_type(), i32_type);
+    assert_eq!(alloca.get_array_size(), i32_type.const_int(1, false));
+    assert!(ptr.as_instruction().unwrap().as_load().is_none());
+
+    let store = store.as_store().unwrap();
+
+    assert_eq!(store.get_value_operand().into_int_value(), seven);
+    assert_eq!(store.get_pointer_operand(), ptr);
+    assert!(!store.is_volatile());
+    assert_eq!(store.as_instruction().get_opcode(), Store);
+
+    let load = value.as_instruction().unwrap().as_load().unwrap();
+
+    assert_eq!(load.get_pointer_operand(), ptr);
+    assert!(!load.is_volatile());
+
+    let call = call_value.as_instruction().unwrap().as_call().unwrap();
+
+    assert_eq!(call.count_arguments(), 1);
+    assert_eq!(call.get_argument(0).unwrap().into_int_value(), value);
+    assert!(call.get_argument(1).is_none());
+    assert_eq!(call.get_called_function(), Some(callee));
+    assert!(!call.is_tail_call());
+
+    let icmp = int_cmp.as_instruction().unwrap().as_icmp().unwrap();
+
+    assert_eq!(icmp.get_predicate(), IntPredicate::SLT);
+    assert_eq!(icmp.get_lhs().into_int_value(), call_value);
+    assert_eq!(icmp.get_rhs().into_int_value(), seven);
+
+    let fcmp = float_cmp.as_instruction().unwrap().as_fcmp().unwrap();
+
+    assert_eq!(fcmp.get_lhs().into_float_value(), float_arg);
+    assert!(float_cmp.as_instruction().unwrap().as_icmp().is_none());
+
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+    assert_eq!(fcmp.get_predicate(), FloatPredicate::OGE);
+
+    let branch = branch.as_branch().unwrap();
+
+    assert!(branch.is_conditional());
+    assert_eq!(branch.get_condition(), Some(int_cmp));
+
+    let successors = branch.get_successors();
+
+    assert_eq!(successors.len(), 2);
+    assert_eq!(successors[0], then_block);
+    assert_eq!(successors[1], else_block);
+
+    let unconditional_branch = unconditional_branch.as_branch().unwrap();
+
+    assert!(!unconditional_branch.is_conditional());
+    assert!(unconditional_branch.get_condition().is_none());
+
+    let successors = unconditional_branch.get_successors();
+
+    assert_eq!(successors.len(), 1);
+    assert_eq!(successors[0], else_block);
+}
+
+#[test]
+fn test_instruction_erase_detach_and_move() {
+    let context = Context::create();
+    let module = context.create_module("moves");
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let i32_type = context.i32_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let function = module.add_function("my_fn", &fn_type, None);
+    let entry = function.append_basic_block("entry");
+    let exit = function.append_basic_block("exit");
+
+    builder.position_at_end(&entry);
+
+    let ptr = builder.build_alloca(&i32_type, "ptr");
+    let alloca = ptr.as_instruction().unwrap();
+    let store = builder.build_store(&ptr, &i32_type.const_int(1, false));
+    let value = builder.build_load(&ptr, "value").into_int_value();
+    let load = value.as_instruction().unwrap();
+    let branch = builder.build_unconditional_branch(&exit);
+
+    builder.position_at_end(&exit);
+
+    let ret = builder.build_return(None);
+
+    // The alloca is still used by the store and load
+    assert!(alloca.erase().is_err());
+    assert_eq!(alloca.get_parent().unwrap(), entry);
+
+    // Moving the load before the store and back again, keeping its name
+    load.move_before(&store, &builder);
+
+    assert_eq!(alloca.get_next_instruction(), Some(load));
+    assert_eq!(load.get_next_instruction(), Some(store));
+    assert_eq!(value.get_name().to_str(), Ok("value"));
+    assert_eq!(builder.get_insert_block().unwrap(), entry);
+
+    load.move_after(&store, &builder);
+
+    assert_eq!(store.get_next_instruction(), Some(load));
+    assert_eq!(load.get_next_instruction(), Some(branch));
+
+    // Moving into another block, both at the end and in the middle of it
+    load.move_after(&ret, &builder);
+
+    assert_eq!(load.get_parent().unwrap(), exit);
+    assert_eq!(ret.get_next_instruction(), Some(load));
+
+    load.move_before(&ret, &builder);
+
+    assert_eq!(load.get_next_instruction(), Some(ret));
+    assert_eq!(exit.get_first_instruction(), Some(load));
+
+    // Detaching keeps the instruction alive so it can be reinserted
+    let detached = load.detach().unwrap();
+
+    assert_eq!(*detached.as_instruction(), load);
+    assert!(load.get_parent().is_none());
+    assert!(load.detach().is_none());
+    assert!(load.erase().is_err());
+    assert_eq!(exit.get_first_instruction(), Some(ret));
+
+    builder.position_before(&branch);
+
+    assert_eq!(builder.insert_detached_instruction(detached), load);
+    assert_eq!(load.get_parent().unwrap(), entry);
+    assert_eq!(store.get_next_instruction(), Some(load));
+    assert_eq!(value.get_name().to_str(), Ok("value"));
+    assert!(module.verify().is_ok());
+
+    assert!(load.erase().is_ok());
+
+    // Detached instructions are deleted through a builder, which stays where it was
+    let detached_store = store.detach().unwrap();
+    let detached_alloca = alloca.detach().unwrap();
+
+    builder.position_before(&branch);
+
+    let detached_alloca = detached_alloca.erase(&builder).unwrap_err();
+
+    assert!(detached_store.erase(&builder).is_ok());
+    assert!(detached_alloca.erase(&builder).is_ok());
+    assert_eq!(entry.get_first_instruction(), Some(branch));
+
+    builder.build_return(None);
+
+    assert_eq!(branch.get_previous_instruction().unwrap().get_opcode(), Return);
+}
+
+#[test]
+fn test_constant_readback() {
+    let context = Context::create();
+    let bool_type = context.bool_type();
+    let i8_type = context.i8_type();
+    let i64_type = context.i64_type();
+    let i128_type = context.i128_type();
+    let f32_type = context.f32_type();
+    let f64_type = context.f64_type();
+    let f128_type = context.f128_type();
+
+    assert_eq!(bool_type.const_int(1, false).get_zero_extended_constant(), Some(1));
+    assert_eq!(bool_type.const_int(1, false).get_sign_extended_constant(), Some(-1));
+    assert_eq!(i8_type.const_int(200, false).get_zero_extended_constant(), Some(200));
+    assert_eq!(i8_type.const_int(200, false).get_sign_extended_constant(), Some(-56));
+    assert_eq!(i64_type.const_int(u64::max_value(), false).get_zero_extended_constant(), Some(u64::max_value()));
+    assert_eq!(i64_type.const_int(u64::max_value(), false).get_sign_extended_constant(), Some(-1));
+    assert_eq!(i64_type.const_int(42, false).get_arbitrary_precision_constant(), Some(vec![42]));
+
+    let i128_val = i128_type.const_int_arbitrary_precision(&[u64::max_value(), 7]);
+
+    assert!(i128_val.get_zero_extended_constant().is_none());
+    assert!(i128_val.get_sign_extended_constant().is_none());
+    assert_eq!(i128_val.get_arbitrary_precision_constant(), Some(vec![u64::max_value(), 7]));
+
+    let i65_val = context.custom_width_int_type(65).const_int_arbitrary_precision(&[3, 1]);
+
+    assert_eq!(i65_val.get_arbitrary_precision_constant(), Some(vec![3, 1]));
+
+    // Folded constants can also be read back
+    let sum = i8_type.const_int(2, false).const_add(&i8_type.const_int(3, false));
+
+    assert_eq!(sum.get_zero_extended_constant(), Some(5));
+
+    assert_eq!(f32_type.const_float(1.5).get_constant(), Some((1.5, false)));
+    assert_eq!(f64_type.const_float(-0.1).get_constant(), Some((-0.1, false)));
+
+    let (f128_constant, lossy) = f128_type.const_float_from_string("0.1").get_constant().unwrap();
+
+    assert!((f128_constant - 0.1).abs() < 1e-15);
+    assert!(lossy);
+
+    // Non constant values can't be read back
+    let module = context.create_module("constants");
+    let builder = context.create_builder();
+    let fn_type = context.void_type().fn_type(&[&i8_type, &f32_type], false);
+    let function = module.add_function("my_fn", &fn_type, None);
+
+    builder.position_at_end(&function.append_basic_block("entry"));
+
+    let int_arg = function.get_first_param().unwrap().into_int_value();
+    let float_arg = function.get_nth_param(1).unwrap().into_float_value();
+
+    assert!(int_arg.get_zero_extended_constant().is_none());
+    assert!(int_arg.get_sign_extended_constant().is_none());
+    assert!(int_arg.get_arbitrary_precision_constant().is_none());
+    assert!(float_arg.get_constant().is_none());
+    assert!(i8_type.get_undef().get_zero_extended_constant().is_none());
+}
+
+#[test]
+fn test_const_arrays_from_slices() {
+    let context = Context::create();
+    let i8_type = context.i8_type();
+    let i32_type = context.i32_type();
+    let f64_type = context.f64_type();
+
+    let string = context.const_string(b"hello", false);
+    let null_terminated_string = context.const_string(b"hello", true);
+
+    assert_eq!(string.get_type(), i8_type.array_type(5));
+    assert_eq!(null_terminated_string.get_type(), i8_type.array_type(6));
+    assert!(string.is_const_string());
+    assert_eq!(string.as_bytes(), Some(&b"hello"[..]));
+    assert_eq!(null_terminated_string.as_bytes(), Some(&b"hello\0"[..]));
+
+    let bytes = i8_type.const_array_from_slice(&[1, 2, 255, 256]);
+
+    assert_eq!(bytes.get_type(), i8_type.array_type(4));
+    assert_eq!(bytes.as_bytes(), Some(&[1, 2, 255, 0][..]));
+
+    let ints = i32_type.const_array_from_slice(&[1, 2, 3]);
+
+    assert_eq!(ints.get_type(), i32_type.array_type(3));
+    assert_eq!(*ints.print_to_string(), *CString::new("[3 x i32] [i32 1, i32 2, i32 3]").unwrap());
+    assert!(!ints.is_const_string());
+    assert!(ints.as_bytes().is_none());
+
+    let floats = f64_type.const_array_from_slice(&[1.0, 2.5]);
+
+    assert_eq!(floats.get_type(), f64_type.array_type(2));
+    assert_eq!(*floats.print_to_string(), *CString::new("[2 x double] [double 1.000000e+00, double 2.500000e+00]").unwrap());
+    assert!(!floats.is_const_string());
+
+    let empty = i32_type.const_array_from_slice(&[]);
+
+    assert_eq!(empty.get_type(), i32_type.array_type(0));
+    assert!(!empty.is_const_string());
+    assert!(i8_type.array_type(2).get_undef().as_bytes().is_none());
+}
+
+#[test]
+fn test_global_linkage_alignment_and_deletion() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let void_type = context.void_type();
+    let global = module.add_global(&i32_type, Some(AddressSpace::Const), "my_global");
+
+    assert_eq!(global.get_linkage(), ExternalLinkage);
+    assert_eq!(global.get_alignment(), 0);
+    assert_eq!(global.get_address_space(), AddressSpace::Const as u32);
+    assert_eq!(global.get_value_type(), AnyTypeEnum::IntType(i32_type));
+
+    global.set_linkage(PrivateLinkage);
+    global.set_alignment(16);
+
+    assert_eq!(global.get_linkage(), PrivateLinkage);
+    assert_eq!(global.get_alignment(), 16);
+
+    let global2 = module.add_global(&i32_type, None, "my_global2");
+
+    assert_eq!(global2.get_address_space(), AddressSpace::Generic as u32);
+
+    let fn_type = void_type.fn_type(&[], false);
+    let function = module.add_function("my_fn", &fn_type, None);
+    let entry = context.append_basic_block(&function, "entry");
+
+    builder.position_at_end(&entry);
+
+    let load = builder.build_load(&global2.as_pointer_value(), "load");
+
+    builder.build_return(None);
+
+    assert!(global2.delete().is_err());
+    assert!(module.get_global("my_global2").is_some());
+
+    load.as_instruction().unwrap().erase().unwrap();
+
+    assert!(global2.delete().is_ok());
+    assert!(module.get_global("my_global2").is_none());
+
+    // Only global variables may be deleted through a GlobalValue
+    assert!(function.as_global_value().delete().is_err());
+    assert!(module.get_function("my_fn").is_some());
+
+    let ir = "@my_global3 = addrspace(2) global i32 0\n";
+    let memory_buffer = MemoryBuffer::create_from_memory_range(ir, "my_fixture.ll");
+    let module = context.create_module_from_ir(memory_buffer).unwrap();
+
+    assert_eq!(module.get_global("my_global3").unwrap().get_address_space(), 2);
+}
+
+#[test]
+fn test_global_value_kinds() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let function = module.add_function("my_fn", &fn_type, None);
+    let global = module.add_global(&i32_type, None, "my_global");
+    let fn_global = function.as_global_value();
+    let alias = module.add_global_alias(&i32_type, &global.as_pointer_value(), "my_alias");
+
+    global.set_initializer(&i32_type.const_int(1, false));
+    global.set_constant(true);
+
+    // Global variable only properties are unavailable on functions and aliases, and setting them does nothing
+    for value in &[fn_global, alias] {
+        value.set_initializer(&i32_type.const_int(2, false));
+        value.set_constant(true);
+        value.set_thread_local(true);
+        value.set_thread_local_mode(Some(ThreadLocalMode::InitialExecTLSModel));
+        value.set_externally_initialized(true);
+
+        assert!(value.get_initializer().is_none());
+        assert!(!value.is_constant());
+        assert!(!value.is_thread_local());
+        assert!(!value.is_externally_initialized());
+        assert!(value.get_thread_local_mode().is_none());
+        assert!(value.get_next_global().is_none());
+        assert!(value.get_previous_global().is_none());
+    }
+
+    // Functions still have a section and alignment, whereas aliases don't
+    fn_global.set_section("my_section");
+    fn_global.set_alignment(16);
+
+    assert_eq!(fn_global.get_section().to_str(), Ok("my_section"));
+    assert_eq!(fn_global.get_alignment(), 16);
+    assert_eq!(alias.get_alignment(), 0);
+
+    alias.set_section("my_section");
+    alias.set_alignment(16);
+
+    assert_eq!(alias.get_alignment(), 0);
+    assert!(global.is_constant());
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_function_deletion_and_clear_body() {
+    use self::inkwell::IntPredicate;
+
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[&i32_type], false);
+    let callee = module.add_function("callee", &fn_type, Some(&InternalLinkage));
+    let caller = module.add_function("caller", &fn_type, None);
+
+    // callee: a recursive body spanning several blocks, with values used across blocks
+    let entry = callee.append_basic_block("entry");
+    let recurse = callee.append_basic_block("recurse");
+    let exit = callee.append_basic_block("exit");
+    let param = callee.get_first_param().unwrap().into_int_value();
+
+    builder.position_at_end(&entry);
+
+    let is_zero = builder.build_int_compare(IntPredicate::EQ, &param, &i32_type.const_int(0, false), "is_zero");
+
+    builder.build_conditional_branch(&is_zero, &exit, &recurse);
+    builder.position_at_end(&recurse);
+
+    let one = i32_type.const_int(1, false);
+    let decremented = builder.build_int_sub(&param, &one, "decremented");
+    let call = builder.build_call(&callee, &[&decremented], "call", false).left().unwrap();
+
+    builder.build_return(Some(&call));
+    builder.position_at_end(&exit);
+    builder.build_return(Some(&param));
+
+    // caller: calls callee
+    let caller_entry = caller.append_basic_block("entry");
+    let caller_param = caller.get_first_param().unwrap();
+
+    builder.position_at_end(&caller_entry);
+
+    let result = builder.build_call(&callee, &[&caller_param], "result", false).left().unwrap();
+
+    builder.build_return(Some(&result));
+
+    assert!(module.verify().is_ok());
+    assert!(callee.delete().is_err());
+    assert!(module.get_function("callee").is_some());
+
+    let personality = module.add_function("personality", &fn_type, None);
+
+    caller.set_personality_function(&personality);
+
+    assert!(caller.has_personality_function());
+
+    caller.clear_body();
+
+    assert_eq!(caller.count_basic_blocks(), 0);
+    assert!(caller.get_first_basic_block().is_none());
+    assert!(!caller.has_personality_function());
+
+    // Functions without a personality are left alone, and clearing twice is harmless
+    assert_eq!(callee.clear_body(), InternalLinkage);
+    assert_eq!(callee.clear_body(), ExternalLinkage);
+
+    assert_eq!(callee.count_basic_blocks(), 0);
+    assert_eq!(callee.get_linkage(), ExternalLinkage);
+    assert!(module.verify().is_ok());
+
+    // A new body can be added to a cleared function
+    let entry = callee.append_basic_block("entry");
+
+    builder.position_at_end(&entry);
+    builder.build_return(Some(&callee.get_first_param().unwrap()));
+    callee.set_linkage(InternalLinkage);
+
+    assert_eq!(callee.get_linkage(), InternalLinkage);
+    assert!(module.verify().is_ok());
+
+    let caller = caller.into_declaration();
+
+    assert_eq!(caller.count_basic_blocks(), 0);
+    assert!(callee.delete().is_ok());
+    assert!(module.get_function("callee").is_none());
+    assert!(caller.delete().is_ok());
+    assert!(module.get_function("caller").is_none());
+    assert!(module.verify().is_ok());
+}